@@ -2,6 +2,7 @@
 
 use std::{
     alloc::Layout,
+    cell::Cell,
     collections::hash_map::DefaultHasher,
     ffi::OsStr,
     hash::{Hash, Hasher},
@@ -11,6 +12,23 @@ use std::{
 use crate::datatype::*;
 use staticize::*;
 
+/// Returns `true` if `a` and `b` are the very same region of memory (same address and
+/// length), in which case they are trivially equal without comparing any elements.
+#[inline]
+fn same_slice<T>(a: &[T], b: &[T]) -> bool {
+    a.len() == b.len() && std::ptr::eq(a.as_ptr(), b.as_ptr())
+}
+
+/// Compares two slices by content, which is the fallback used when two distinct [`Static`]s
+/// have to be compared (e.g. on a hash collision). Identical memory is detected without
+/// touching any elements, and slices of different lengths are rejected up front. Otherwise
+/// this is plain slice equality, which for byte slices (and other plain integer slices)
+/// compiles down to a single vectorized `memcmp` rather than an element-by-element loop.
+#[inline]
+fn slices_eq<T: PartialEq>(a: &[T], b: &[T]) -> bool {
+    same_slice(a, b) || a == b
+}
+
 /// An unsafe internal struct used to represent a type-erased, heap-allocated, static value
 /// (i.e. not a reference or slice).
 #[derive(Copy, Clone)]
@@ -20,9 +38,11 @@ pub struct StaticValue {
 }
 
 impl StaticValue {
-    /// Allows (unsafe) direct access to the value stored in this [`StaticValue`]. Specifying a
-    /// `T` that differs from the type of the value actually stored in the [`StaticValue`] is
-    /// UB.
+    /// Allows (unsafe) direct access to the value stored in this [`StaticValue`].
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type of the value actually stored in the [`StaticValue`].
     pub const unsafe fn as_value<'a, T>(&self) -> &'a T {
         &*(self.ptr as *const T)
     }
@@ -47,6 +67,19 @@ impl StaticValue {
         let ptr = (Box::leak(Box::from(value)) as *const T) as *const ();
         StaticValue { ptr, hash }
     }
+
+    /// Creates a new [`StaticValue`] pointing at an existing `'static` value rather than a
+    /// fresh heap allocation, based on a manually-specified hashcode. Unlike
+    /// [`StaticValue::with_hash`], this does not leak any memory.
+    pub fn from_static_ref<T: Hash>(value: &'static T, hash: Option<u64>) -> Self {
+        let hash = hash.unwrap_or_else(|| {
+            let mut hasher = DefaultHasher::default();
+            value.hash(&mut hasher);
+            hasher.finish()
+        });
+        let ptr = (value as *const T) as *const ();
+        StaticValue { ptr, hash }
+    }
 }
 
 impl PartialEq for StaticValue {
@@ -65,7 +98,7 @@ impl Hash for StaticValue {
 
 impl PartialOrd for StaticValue {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.hash.partial_cmp(&other.hash)
+        Some(self.cmp(other))
     }
 }
 
@@ -92,9 +125,11 @@ pub struct StaticSlice {
 }
 
 impl StaticSlice {
-    /// Allows (unsafe) direct access to the slice stored in this [`StaticSlice`]. Specifying a
-    /// `T` that differs from the type of the slice actually stored in the [`StaticSlice`] is
-    /// UB.
+    /// Allows (unsafe) direct access to the slice stored in this [`StaticSlice`].
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the element type of the slice actually stored in the [`StaticSlice`].
     pub unsafe fn as_slice<'a, T>(&self) -> &'a [T] {
         std::slice::from_raw_parts(self.ptr as *const T, self.len())
     }
@@ -102,7 +137,13 @@ impl StaticSlice {
     /// Returns the length of the slice stored in this [`StaticSlice`].
     #[inline]
     pub const fn len(&self) -> usize {
-        unsafe { (*self.ptr).len() }
+        self.ptr.len()
+    }
+
+    /// Returns `true` if the slice stored in this [`StaticSlice`] has a length of `0`.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
     /// Creates a new [`StaticSlice`] from the specified `slice`, which must be hashable. Since
@@ -122,12 +163,17 @@ impl StaticSlice {
             slice.hash(&mut hasher);
             hasher.finish()
         });
-        let ptr = unsafe {
-            let ptr = std::alloc::alloc(Layout::array::<T>(slice.len()).unwrap()) as *mut T;
-            std::ptr::copy(slice.as_ptr(), ptr, slice.len());
-            ptr
+        let layout = Layout::array::<T>(slice.len()).unwrap();
+        let ptr = match layout.size() {
+            // zero-sized allocations are UB, so empty slices use a dangling (aligned) pointer
+            0 => std::ptr::NonNull::<T>::dangling().as_ptr(),
+            _ => unsafe {
+                let ptr = std::alloc::alloc(layout) as *mut T;
+                std::ptr::copy(slice.as_ptr(), ptr, slice.len());
+                ptr
+            },
         };
-        let ptr = unsafe { std::slice::from_raw_parts(ptr, slice.len()) } as *const [T];
+        let ptr = std::ptr::slice_from_raw_parts(ptr, slice.len());
         let ptr = ptr as *const [()];
         StaticSlice { ptr, hash }
     }
@@ -149,7 +195,7 @@ impl Eq for StaticSlice {}
 
 impl PartialOrd for StaticSlice {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.hash.partial_cmp(&other.hash)
+        Some(self.cmp(other))
     }
 }
 
@@ -167,9 +213,61 @@ impl std::fmt::Debug for StaticSlice {
     }
 }
 
+/// Strings of at most this many bytes are packed into a shared thread-local chunk rather
+/// than leaked into their own heap allocation.
+pub const SMALL_STR_MAX_LEN: usize = 15;
+
+/// Size in bytes of the first chunk that short strings are packed into on each thread. Each
+/// subsequent chunk is twice as large as the previous one, up to [`SMALL_STR_MAX_CHUNK_LEN`],
+/// so threads that only intern a few short strings only leak a few bytes.
+const SMALL_STR_FIRST_CHUNK_LEN: usize = 64;
+
+/// Size in bytes beyond which chunks for short strings stop growing.
+const SMALL_STR_MAX_CHUNK_LEN: usize = 4096;
+
+thread_local! {
+    /// The unused tail (start and length) of the current thread's chunk for short strings,
+    /// along with the size of that chunk. Chunks are leaked and never written to again once
+    /// their bytes are handed out, so the strings packed into them are `'static` and keep a
+    /// unique, stable address.
+    static SMALL_STRS: Cell<(*mut u8, usize, usize)> = const { Cell::new((std::ptr::null_mut(), 0, 0)) };
+}
+
+/// Copies a non-empty string of at most [`SMALL_STR_MAX_LEN`] bytes into the current
+/// thread's chunk for short strings, starting a new chunk if the current one is full.
+/// Returns `None` if the thread-local chunk is unavailable (i.e. during thread teardown).
+///
+/// Short strings can't be stored inline in [`StaticStr`] itself, since interned strings are
+/// handed out as `&'static str` and a reference into a [`Copy`] handle would not outlive it.
+fn pack_small_str(value: &str) -> Option<*const str> {
+    SMALL_STRS
+        .try_with(|tail| {
+            let (mut start, mut remaining, mut chunk_len) = tail.get();
+            if remaining < value.len() {
+                chunk_len =
+                    (chunk_len * 2).clamp(SMALL_STR_FIRST_CHUNK_LEN, SMALL_STR_MAX_CHUNK_LEN);
+                let chunk = Box::leak(vec![0u8; chunk_len].into_boxed_slice());
+                (start, remaining) = (chunk.as_mut_ptr(), chunk.len());
+            }
+            // SAFETY: `start..start + remaining` is an unused region of a leaked chunk, which
+            // is never handed out again once `tail` is advanced past it.
+            unsafe {
+                std::ptr::copy_nonoverlapping(value.as_ptr(), start, value.len());
+                tail.set((start.add(value.len()), remaining - value.len(), chunk_len));
+                let bytes = std::slice::from_raw_parts(start, value.len());
+                std::str::from_utf8_unchecked(bytes) as *const str
+            }
+        })
+        .ok()
+}
+
 /// An internal struct used to represent a type-erased, heap-allocated, static string
 /// (`&'static str`).
 ///
+/// Short strings (see [`SMALL_STR_MAX_LEN`]) are packed together into shared chunks instead
+/// of each getting a heap allocation of their own, but like all other strings they still
+/// have a stable, unique address once interned.
+///
 /// [`StaticStr`] is the only variant of [`Static`] where all methods are inherently safe,
 /// because no type erasure occurs.
 #[derive(Copy, Clone)]
@@ -181,7 +279,7 @@ pub struct StaticStr {
 impl StaticStr {
     /// Allows direct access to the string stored in this [`StaticStr`].
     pub const fn as_str<'a>(&self) -> &'a str {
-        unsafe { &*(self.ptr as *const str) }
+        unsafe { &*self.ptr }
     }
 
     /// Creates a new [`StaticStr`] from the specified `&str`. Since [`StaticStr`] does not
@@ -201,8 +299,12 @@ impl StaticStr {
             value.hash(&mut hasher);
             hasher.finish()
         });
-        let ptr = Box::leak(Box::from(value)) as *const str;
-        let written_value = unsafe { (ptr as *const str).as_ref().unwrap() };
+        let packed = match value.len() {
+            1..=SMALL_STR_MAX_LEN => pack_small_str(value),
+            _ => None,
+        };
+        let ptr = packed.unwrap_or_else(|| Box::leak(Box::from(value)) as *const str);
+        let written_value = unsafe { ptr.as_ref().unwrap() };
         assert_eq!(written_value, value);
         StaticStr { ptr, hash }
     }
@@ -224,7 +326,7 @@ impl Eq for StaticStr {}
 
 impl PartialOrd for StaticStr {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.hash.partial_cmp(&other.hash)
+        Some(self.cmp(other))
     }
 }
 
@@ -256,7 +358,7 @@ pub struct StaticOsStr {
 impl StaticOsStr {
     /// Allows direct access to the [`OsStr`] stored in this [`StaticOsStr`].
     pub const fn as_os_str<'a>(&self) -> &'a OsStr {
-        unsafe { &*(self.ptr as *const OsStr) }
+        unsafe { &*self.ptr }
     }
 
     /// Creates a new [`StaticOsStr`] from the specified `&OsStr`. Since [`StaticOsStr`] does
@@ -277,7 +379,7 @@ impl StaticOsStr {
             hasher.finish()
         });
         let ptr = Box::leak(Box::from(value)) as *const OsStr;
-        let written_value = unsafe { (ptr as *const OsStr).as_ref().unwrap() };
+        let written_value = unsafe { ptr.as_ref().unwrap() };
         assert_eq!(written_value, value);
         StaticOsStr { ptr, hash }
     }
@@ -299,7 +401,7 @@ impl Eq for StaticOsStr {}
 
 impl PartialOrd for StaticOsStr {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.hash.partial_cmp(&other.hash)
+        Some(self.cmp(other))
     }
 }
 
@@ -330,7 +432,7 @@ pub struct StaticPath {
 impl StaticPath {
     /// Allows direct access to the [`Path`] stored in this [`StaticPath`].
     pub const fn as_path<'a>(&self) -> &'a Path {
-        unsafe { &*(self.ptr as *const Path) }
+        unsafe { &*self.ptr }
     }
 
     /// Creates a new [`StaticPath`] from the specified `&Path`. Since [`StaticPath`] does not
@@ -351,7 +453,7 @@ impl StaticPath {
             hasher.finish()
         });
         let ptr = Box::leak(Box::from(value)) as *const Path;
-        let written_value = unsafe { (ptr as *const Path).as_ref().unwrap() };
+        let written_value = unsafe { ptr.as_ref().unwrap() };
         assert_eq!(written_value, value);
         StaticPath { ptr, hash }
     }
@@ -373,7 +475,7 @@ impl Eq for StaticPath {}
 
 impl PartialOrd for StaticPath {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.hash.partial_cmp(&other.hash)
+        Some(self.cmp(other))
     }
 }
 
@@ -440,6 +542,11 @@ impl Static {
         Static::Value(StaticValue::with_hash(value, hash))
     }
 
+    /// Creates a [`Static`] pointing at an existing `'static` value, without allocating.
+    pub fn from_static_ref<T: Hash>(value: &'static T, hash: Option<u64>) -> Static {
+        Static::Value(StaticValue::from_static_ref(value, hash))
+    }
+
     /// Creates a [`Static`] from a `&str`.
     pub fn from_str(value: &str, hash: Option<u64>) -> Static {
         Static::Str(StaticStr::with_hash(value, hash))
@@ -457,7 +564,10 @@ impl Static {
 
     /// Unsafely accesses the slice pointed to by the underlying [`StaticSlice`]. If the
     /// underlying variant of the [`Static`] is not a [`StaticSlice`], this method will panic.
-    /// Specifying the wrong `T` is UB.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the element type of the stored slice.
     pub unsafe fn as_slice<'a, T>(&self) -> &'a [T] {
         match self {
             Static::Slice(static_slice) => static_slice.as_slice::<T>(),
@@ -467,7 +577,10 @@ impl Static {
 
     /// Unsafely accesses the value pointed to by the underlying [`StaticValue`]. If the
     /// underlying variant of the [`Static`] is not a [`StaticValue`], this method will panic.
-    /// Specifying the wrong `T` is UB.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type of the stored value.
     pub unsafe fn as_value<'a, T>(&self) -> &'a T {
         match self {
             Static::Value(static_value) => static_value.as_value::<T>(),
@@ -504,48 +617,146 @@ impl Static {
         }
     }
 
-    /// This is UB if the underlying types differ and a hash collision occurs.
+    /// Compares two interned [`Static`]s for equality. Since the interner stores exactly one
+    /// [`Static`] per distinct value (even when hash codes collide), equal values always share
+    /// a heap address, so this compares addresses (and hash codes, to tell apart zero-sized
+    /// slices of different lengths, which share a dangling address).
+    ///
+    /// # Safety
+    ///
+    /// Both [`Static`]s must have been created from values of type `T`, since the values may
+    /// be compared by content (under `--cfg interned_no_intern`).
     pub unsafe fn _partial_eq<T: PartialEq + DataType + Staticize>(&self, other: &Static) -> bool
     where
         T::SliceValueType: PartialEq,
     {
-        self.hash_code() == other.hash_code()
+        if cfg!(interned_no_intern) {
+            // nothing is de-duplicated in pass-through mode, so addresses say nothing
+            return self._is_same(other) || self._content_eq::<T>(other);
+        }
+        self._is_same(other)
+    }
+
+    /// Returns `true` if `self` and `other` are handles to the very same stored value, which
+    /// (by address and hash code, see [`Static::_partial_eq`]) is an _O(1)_ check regardless
+    /// of the size of the value.
+    #[inline]
+    fn _is_same(&self, other: &Static) -> bool {
+        self.as_ptr() == other.as_ptr() && self.hash_code() == other.hash_code()
+    }
+
+    /// Compares the values stored in two [`Static`]s by content.
+    ///
+    /// # Safety
+    ///
+    /// Both [`Static`]s must have been created from values of type `T`.
+    pub unsafe fn _content_eq<T: PartialEq + DataType>(&self, other: &Static) -> bool
+    where
+        T::SliceValueType: PartialEq,
+    {
+        match (self, other) {
+            (Static::Value(a), Static::Value(b)) => a.as_value::<T>() == b.as_value::<T>(),
+            (Static::Slice(a), Static::Slice(b)) => slices_eq(
+                a.as_slice::<T::SliceValueType>(),
+                b.as_slice::<T::SliceValueType>(),
+            ),
+            (Static::Str(a), Static::Str(b)) => a.as_str() == b.as_str(),
+            (Static::OsStr(a), Static::OsStr(b)) => a.as_os_str() == b.as_os_str(),
+            (Static::Path(a), Static::Path(b)) => a.as_path() == b.as_path(),
+            _ => false,
+        }
     }
 
-    /// This is UB if the underlying `T` is specified incorrectly
-    pub unsafe fn _partial_cmp<T: PartialOrd + Staticize>(
+    /// Compares the value stored in this [`Static`] with `value` by content.
+    ///
+    /// # Safety
+    ///
+    /// This [`Static`] must have been created from a value of type `T`.
+    pub unsafe fn _eq_value<T: PartialEq + DataType>(&self, value: &T) -> bool {
+        let stored: T = match self {
+            Static::Value(stored) => return stored.as_value::<T>() == value,
+            Static::Slice(stored) => {
+                let stored = stored.as_slice::<T::SliceValueType>();
+                if same_slice(stored, value.as_slice()) {
+                    // e.g. re-interning an interned slice, which is trivially equal to itself
+                    return true;
+                }
+                std::mem::transmute_copy(&stored)
+            }
+            Static::Str(stored) => std::mem::transmute_copy(&stored.as_str()),
+            Static::OsStr(stored) => std::mem::transmute_copy(&stored.as_os_str()),
+            Static::Path(stored) => std::mem::transmute_copy(&stored.as_path()),
+        };
+        let eq = stored == *value;
+        // `stored` is a copied reference, so there is nothing to drop
+        std::mem::forget(stored);
+        eq
+    }
+
+    /// Compares two [`Static`]s by value (slices element-wise, lexicographically, via
+    /// `T::SliceValueType`), never by hash code or address.
+    ///
+    /// # Safety
+    ///
+    /// Both [`Static`]s must have been created from values of type `T`.
+    pub unsafe fn _partial_cmp<T: PartialOrd + Staticize + DataType>(
         &self,
         other: &Self,
-    ) -> Option<std::cmp::Ordering> {
+    ) -> Option<std::cmp::Ordering>
+    where
+        T::SliceValueType: PartialOrd,
+    {
+        if self._is_same(other) {
+            // the very same interned value, so skip comparing (potentially large) contents
+            return Some(std::cmp::Ordering::Equal);
+        }
         match (self, other) {
             (Static::Value(a), Static::Value(b)) => {
                 a.as_value::<T>().partial_cmp(b.as_value::<T>())
             }
-            (Static::Slice(a), Static::Slice(b)) => {
-                a.as_slice::<T>().partial_cmp(b.as_slice::<T>())
-            }
+            (Static::Slice(a), Static::Slice(b)) => a
+                .as_slice::<T::SliceValueType>()
+                .partial_cmp(b.as_slice::<T::SliceValueType>()),
             (Static::Str(a), Static::Str(b)) => a.as_str().partial_cmp(b.as_str()),
             (Static::OsStr(a), Static::OsStr(b)) => a.as_os_str().partial_cmp(b.as_os_str()),
             (Static::Path(a), Static::Path(b)) => a.as_path().partial_cmp(b.as_path()),
-            _ => (T::static_type_id(), self.hash_code())
-                .partial_cmp(&(T::static_type_id(), other.hash_code())),
+            // the variant is determined by `T`, so same-`T` operands always match above
+            _ => unreachable!("interned values of the same type have the same representation"),
         }
     }
 
-    /// This is UB if the underlying `T` is specified incorrectly
-    pub unsafe fn _cmp<T: Ord + Staticize>(&self, other: &Self) -> std::cmp::Ordering {
+    /// Compares two [`Static`]s by value (slices element-wise, lexicographically, via
+    /// `T::SliceValueType`), never by hash code or address.
+    ///
+    /// # Safety
+    ///
+    /// Both [`Static`]s must have been created from values of type `T`.
+    pub unsafe fn _cmp<T: Ord + Staticize + DataType>(&self, other: &Self) -> std::cmp::Ordering
+    where
+        T::SliceValueType: Ord,
+    {
+        if self._is_same(other) {
+            // the very same interned value, so skip comparing (potentially large) contents
+            return std::cmp::Ordering::Equal;
+        }
         match (self, other) {
             (Static::Value(a), Static::Value(b)) => a.as_value::<T>().cmp(b.as_value::<T>()),
-            (Static::Slice(a), Static::Slice(b)) => a.as_slice::<T>().cmp(b.as_slice::<T>()),
+            (Static::Slice(a), Static::Slice(b)) => a
+                .as_slice::<T::SliceValueType>()
+                .cmp(b.as_slice::<T::SliceValueType>()),
             (Static::Str(a), Static::Str(b)) => a.as_str().cmp(b.as_str()),
             (Static::OsStr(a), Static::OsStr(b)) => a.as_os_str().cmp(b.as_os_str()),
             (Static::Path(a), Static::Path(b)) => a.as_path().cmp(b.as_path()),
-            _ => (T::static_type_id(), self.hash_code())
-                .cmp(&(T::static_type_id(), other.hash_code())),
+            // the variant is determined by `T`, so same-`T` operands always match above
+            _ => unreachable!("interned values of the same type have the same representation"),
         }
     }
 
-    /// This is UB if the underlying `T` is specified incorrectly
+    /// Hashes this [`Static`] together with the [`TypeId`](std::any::TypeId) of `T`.
+    ///
+    /// # Safety
+    ///
+    /// This [`Static`] must have been created from a value of type `T`.
     pub unsafe fn _hash<T: Hash + Staticize, H: Hasher>(&self, state: &mut H) {
         let type_id = T::static_type_id();
         match self {
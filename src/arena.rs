@@ -0,0 +1,180 @@
+//! Home of [`ArenaInterned`], an opt-in interned handle that stores all values of a type
+//! contiguously.
+//!
+//! Regular [`Interned`] values are each leaked into their own heap allocation, so scanning
+//! every interned value of a type hops all over the heap. [`ArenaInterned`] instead
+//! bump-allocates values of a single type into large contiguous chunks, in insertion order,
+//! which makes walking them all (see [`ArenaInterned::iter`]) cache-friendly.
+//!
+//! ```
+//! use interned::ArenaInterned;
+//!
+//! let a = ArenaInterned::from(7u32);
+//! let b = ArenaInterned::from(7u32);
+//! assert_eq!(a.as_ptr(), b.as_ptr());
+//! assert_eq!(*a.interned_value(), 7);
+//! assert!(ArenaInterned::<u32>::iter().any(|value| value == a));
+//! ```
+//!
+//! Like [`Interned`], arenas are thread-local and values are never freed. Handles may be sent
+//! across threads; equal values from different threads' arenas still compare equal, they just
+//! don't share an address.
+
+use crate::*;
+use std::{any::Any, mem::ManuallyDrop};
+
+/// Number of values in the first chunk of each arena. Each subsequent chunk is twice as large
+/// as the previous one.
+const FIRST_CHUNK_LEN: usize = 64;
+
+thread_local! {
+    /// Internal thread-local storage of one [`Arena`] per type, keyed by [`TypeId`].
+    static ARENAS: RefCell<HashMap<TypeId, Box<dyn Any>, TypeIdHasherBuilder>> = const { RefCell::new(HashMap::with_hasher(TypeIdHasherBuilder)) };
+}
+
+/// Internal per-type storage backing [`ArenaInterned`].
+struct Arena<T: 'static> {
+    /// Chunks of values in insertion order. A chunk is never pushed to beyond its capacity, so
+    /// its values never move, and chunks are never dropped, so references into them are
+    /// `'static`.
+    chunks: Vec<ManuallyDrop<Vec<T>>>,
+    /// Maps each value to its (unique) location in `chunks`.
+    index: HashMap<T, &'static T>,
+}
+
+impl<T: Hash + Eq + Copy + 'static> Arena<T> {
+    fn intern(&mut self, value: T) -> &'static T {
+        if let Some(&existing) = self.index.get(&value) {
+            return existing;
+        }
+        let needs_chunk = self
+            .chunks
+            .last()
+            .is_none_or(|chunk| chunk.len() == chunk.capacity());
+        if needs_chunk {
+            let len = self
+                .chunks
+                .last()
+                .map_or(FIRST_CHUNK_LEN, |chunk| chunk.capacity() * 2);
+            self.chunks.push(ManuallyDrop::new(Vec::with_capacity(len)));
+        }
+        let chunk = self.chunks.last_mut().unwrap();
+        chunk.push(value);
+        // SAFETY: the chunk never reallocates (it is never pushed to beyond its capacity) and
+        // is never dropped, so the pushed value stays valid at this address forever.
+        let stored: &'static T = unsafe { &*chunk.as_ptr().add(chunk.len() - 1) };
+        self.index.insert(value, stored);
+        stored
+    }
+
+    fn snapshot(&self) -> Vec<&'static [T]> {
+        self.chunks
+            .iter()
+            // SAFETY: see `Arena::intern`; the first `len` values of a chunk never move.
+            .map(|chunk| unsafe { std::slice::from_raw_parts(chunk.as_ptr(), chunk.len()) })
+            .collect()
+    }
+}
+
+/// Runs `f` on the thread-local [`Arena`] for `T`, creating it if needed.
+fn with_arena<T: Hash + Eq + Copy + 'static, R>(f: impl FnOnce(&mut Arena<T>) -> R) -> R {
+    ARENAS.with(|arenas| {
+        let mut arenas = arenas.borrow_mut();
+        let arena = arenas.entry(TypeId::of::<T>()).or_insert_with(|| {
+            Box::new(Arena::<T> {
+                chunks: Vec::new(),
+                index: HashMap::new(),
+            })
+        });
+        f(arena.downcast_mut().unwrap())
+    })
+}
+
+/// An interned value of type `T` stored contiguously with all other arena-interned values of
+/// type `T`. Equal values share the same address, just like [`Interned`].
+///
+/// See the [module docs](self) for more information.
+pub struct ArenaInterned<T: 'static> {
+    value: &'static T,
+}
+
+impl<T: Hash + Eq + Copy + 'static> ArenaInterned<T> {
+    /// Interns `value` into the thread-local arena for `T`, returning the existing handle if an
+    /// equal value was interned before.
+    pub fn from(value: T) -> Self {
+        ArenaInterned {
+            value: with_arena(|arena| arena.intern(value)),
+        }
+    }
+
+    /// Iterates over every value of type `T` arena-interned on the current thread, in
+    /// insertion order. Values interned while iterating are not visited.
+    pub fn iter() -> impl Iterator<Item = ArenaInterned<T>> {
+        with_arena(|arena: &mut Arena<T>| arena.snapshot())
+            .into_iter()
+            .flatten()
+            .map(|value| ArenaInterned { value })
+    }
+}
+
+impl<T: 'static> ArenaInterned<T> {
+    /// Returns a reference to the underlying arena-interned value.
+    pub fn interned_value(&self) -> &'static T {
+        self.value
+    }
+
+    /// Returns the address of the underlying value within its arena.
+    pub fn as_ptr(&self) -> *const () {
+        self.value as *const T as *const ()
+    }
+}
+
+impl<T: Hash + Eq + Copy + 'static> From<T> for ArenaInterned<T> {
+    fn from(value: T) -> Self {
+        ArenaInterned::from(value)
+    }
+}
+
+impl<T: 'static> Copy for ArenaInterned<T> {}
+
+impl<T: 'static> Clone for ArenaInterned<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: PartialEq + 'static> PartialEq for ArenaInterned<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // equal values share an address within an arena, but handles may come from the arenas
+        // of different threads, so fall back to comparing values
+        self.as_ptr() == other.as_ptr() || self.value == other.value
+    }
+}
+
+impl<T: Eq + 'static> Eq for ArenaInterned<T> {}
+
+impl<T: Hash + 'static> Hash for ArenaInterned<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<T: 'static> Deref for ArenaInterned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<T: std::fmt::Debug + 'static> std::fmt::Debug for ArenaInterned<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ArenaInterned").field(self.value).finish()
+    }
+}
+
+impl<T: Display + 'static> Display for ArenaInterned<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
@@ -0,0 +1,73 @@
+//! Home of [`ByPtr`], a wrapper that hashes and compares an [`Interned`] value by its heap
+//! address, which makes it a cheap key for [`HashMap`]s and similar collections.
+
+use crate::*;
+
+/// Wraps an [`Interned`] value so that [`Hash`] and [`Eq`] are based on its heap address
+/// ([`Interned::as_ptr`]) rather than on its contents.
+///
+/// This is valid because equal values interned on the same thread always share a heap
+/// address, and it is faster than content hashing for long strings and slices, since hashing
+/// a [`ByPtr`] key is O(1) regardless of the size of the interned value.
+///
+/// **Caveat:** [`Interned`] values live in a _thread-local_ pool, and the same value interned
+/// on two different threads has two different addresses. A [`ByPtr`] is therefore only
+/// meaningful alongside other handles from the same thread's pool.
+///
+/// ```
+/// use interned::{ByPtr, Interned};
+/// use std::collections::HashMap;
+///
+/// let mut map: HashMap<ByPtr<&str>, usize> = HashMap::new();
+/// map.insert(Interned::from("hello").into(), 1);
+/// assert_eq!(map.get(&ByPtr(Interned::from("hello"))), Some(&1));
+/// assert_eq!(map.get(&ByPtr(Interned::from("world"))), None);
+/// ```
+#[derive(Copy, Clone)]
+pub struct ByPtr<T: Hash>(pub Interned<T>);
+
+impl<T: Hash> ByPtr<T> {
+    /// Returns the wrapped [`Interned`] value.
+    pub fn into_inner(self) -> Interned<T> {
+        self.0
+    }
+}
+
+impl<T: Hash> From<Interned<T>> for ByPtr<T> {
+    fn from(value: Interned<T>) -> Self {
+        ByPtr(value)
+    }
+}
+
+impl<T: Hash> PartialEq for ByPtr<T> {
+    fn eq(&self, other: &Self) -> bool {
+        // zero-sized slices of different lengths share a (dangling) address, so the hash code
+        // is compared as well to tell them apart
+        self.0.as_ptr() == other.0.as_ptr() && self.0.value.hash_code() == other.0.value.hash_code()
+    }
+}
+
+impl<T: Hash> Eq for ByPtr<T> {}
+
+impl<T: Hash> Hash for ByPtr<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_ptr().hash(state)
+    }
+}
+
+impl<T: Hash> Deref for ByPtr<T> {
+    type Target = Interned<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Hash> std::fmt::Debug for ByPtr<T>
+where
+    Interned<T>: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ByPtr").field(&self.0).finish()
+    }
+}
@@ -25,6 +25,8 @@ impl DataTypeTypeMarker for Reference {}
 /// An (unsafe) trait that must be implemented on any `T` used with [`Interned`] and/or
 /// [`Memoized`] that provides utility access to underlying variants of the type.
 ///
+/// # Safety
+///
 /// Implementers are responsible for accurately implementing each associated type based on its
 /// description. Doing so inaccurately (for example, setting `SliceType` to something
 /// completely unrelated to `T` when `Type` is [`Slice`]) is UB. Implementers are also
@@ -87,15 +89,15 @@ unsafe impl<'a, T: Sized + Hash + Copy> DataType for &'a [T] {
     type DerefTargetType = [T];
 
     fn as_slice(&self) -> &'a [T] {
-        *self
+        self
     }
 
     fn as_value(&self) -> &'a [T] {
-        *self
+        self
     }
 
     fn to_static_with_hash(&self, hash: Option<u64>) -> Static {
-        Static::from(*self, hash)
+        Static::from(self, hash)
     }
 }
 
@@ -141,11 +143,11 @@ unsafe impl<'a> DataType for &'a str {
     }
 
     fn as_value(&self) -> &'a str {
-        *self
+        self
     }
 
     fn to_static_with_hash(&self, hash: Option<u64>) -> Static {
-        Static::from_str(*self, hash)
+        Static::from_str(self, hash)
     }
 }
 
@@ -162,11 +164,11 @@ unsafe impl<'a> DataType for &'a OsStr {
     }
 
     fn as_value(&self) -> &'a OsStr {
-        *self
+        self
     }
 
     fn to_static_with_hash(&self, hash: Option<u64>) -> Static {
-        Static::from_os_str(*self, hash)
+        Static::from_os_str(self, hash)
     }
 }
 
@@ -183,11 +185,11 @@ unsafe impl<'a> DataType for &'a Path {
     }
 
     fn as_value(&self) -> &'a Path {
-        *self
+        self
     }
 
     fn to_static_with_hash(&self, hash: Option<u64>) -> Static {
-        Static::from_path(*self, hash)
+        Static::from_path(self, hash)
     }
 }
 
@@ -0,0 +1,86 @@
+//! Contains thin wrappers around [`chrono`] date/time types that can be used with
+//! [`Interned`] and [`Memoized`]. Enabled by the `chrono` feature.
+//!
+//! The [`chrono`] types cannot be used with [`Interned`] directly because neither
+//! [`Staticize`] nor the [`chrono`] types are local to this crate, so a `#[repr(transparent)]`
+//! wrapper is provided for each of them. Each wrapper converts freely to and from the type it
+//! wraps, and dereferences to it.
+//!
+//! ```
+//! use interned::{datetime::NaiveDate, Interned};
+//!
+//! let a: Interned<NaiveDate> = chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap().into();
+//! let b: Interned<NaiveDate> = chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap().into();
+//! assert_eq!(a, b);
+//! assert_eq!(a.as_ptr(), b.as_ptr());
+//! assert_eq!(a.to_string(), "2024-02-29");
+//! ```
+
+use crate::*;
+
+/// Generates a `#[repr(transparent)]` wrapper around the specified [`chrono`] type, along
+/// with the impls needed to use it with [`Interned`] and [`Memoized`].
+macro_rules! chrono_wrapper {
+    ($(#[$attr:meta])* $name:ident($inner:ty)) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        pub struct $name(pub $inner);
+
+        derive_staticize!($name);
+        unsafe_impl_data_type!($name, Value);
+        derive_from_interned_impl_value!($name);
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl Deref for $name {
+            type Target = $inner;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl From<$inner> for $name {
+            fn from(value: $inner) -> Self {
+                $name(value)
+            }
+        }
+
+        impl From<$name> for $inner {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl From<$inner> for Interned<$name> {
+            fn from(value: $inner) -> Self {
+                Interned::from($name(value))
+            }
+        }
+    };
+}
+
+chrono_wrapper!(
+    /// A `#[repr(transparent)]` wrapper around [`chrono::NaiveDate`] implementing
+    /// [`DataType`] and [`Staticize`], allowing dates to be interned and memoized.
+    NaiveDate(chrono::NaiveDate)
+);
+
+chrono_wrapper!(
+    /// A `#[repr(transparent)]` wrapper around [`chrono::NaiveDateTime`] implementing
+    /// [`DataType`] and [`Staticize`], allowing date-times to be interned and memoized.
+    NaiveDateTime(chrono::NaiveDateTime)
+);
+
+chrono_wrapper!(
+    /// A `#[repr(transparent)]` wrapper around [`chrono::DateTime<Utc>`] implementing
+    /// [`DataType`] and [`Staticize`], allowing UTC timestamps to be interned and memoized.
+    ///
+    /// [`chrono::DateTime<Utc>`]: chrono::DateTime
+    DateTimeUtc(chrono::DateTime<chrono::Utc>)
+);
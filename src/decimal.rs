@@ -0,0 +1,102 @@
+//! Contains [`Decimal`], a thin wrapper around [`rust_decimal::Decimal`] that can be used with
+//! [`Interned`] and [`Memoized`]. Enabled by the `rust_decimal` feature.
+//!
+//! [`rust_decimal::Decimal`] cannot be used with [`Interned`] directly because neither
+//! [`Staticize`] nor [`rust_decimal::Decimal`] is local to this crate, so [`Decimal`] is
+//! provided instead. It is `#[repr(transparent)]`, converts freely to and from
+//! [`rust_decimal::Decimal`], and dereferences to it.
+//!
+//! ```
+//! use interned::{decimal::Decimal, Interned};
+//! use std::str::FromStr;
+//!
+//! let a: Interned<Decimal> = rust_decimal::Decimal::from_str("1.50").unwrap().into();
+//! let b: Interned<Decimal> = rust_decimal::Decimal::from_str("1.50").unwrap().into();
+//! let c: Interned<Decimal> = rust_decimal::Decimal::from_str("1.5").unwrap().into();
+//! assert_eq!(a.as_ptr(), b.as_ptr());
+//! // the scale is preserved, so `1.50` and `1.5` are interned separately
+//! assert_ne!(a, c);
+//! assert_eq!(a.to_string(), "1.50");
+//! assert_eq!(**a.interned_value(), **c.interned_value());
+//! assert!(a.is_sign_positive());
+//! ```
+
+use crate::*;
+
+/// A `#[repr(transparent)]` wrapper around [`rust_decimal::Decimal`] implementing
+/// [`DataType`] and [`Staticize`], allowing decimals to be interned and memoized.
+///
+/// Unlike [`rust_decimal::Decimal`], equality and hashing take the scale into account
+/// (comparing the mantissa and scale), so decimals that differ only in scale (e.g. `1.5` and
+/// `1.50`) intern to separate values and always read back with the scale they were interned
+/// with. Ordering is by numeric value, with ties broken by scale. To compare numerically,
+/// dereference to the underlying [`rust_decimal::Decimal`] (or [`normalize`] first).
+///
+/// [`normalize`]: rust_decimal::Decimal::normalize
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Decimal(pub rust_decimal::Decimal);
+
+derive_staticize!(Decimal);
+unsafe_impl_data_type!(Decimal, Value);
+derive_from_interned_impl_value!(Decimal);
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.mantissa() == other.0.mantissa() && self.0.scale() == other.0.scale()
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0
+            .cmp(&other.0)
+            .then_with(|| self.0.scale().cmp(&other.0.scale()))
+    }
+}
+
+impl Hash for Decimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.0.mantissa(), self.0.scale()).hash(state)
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Deref for Decimal {
+    type Target = rust_decimal::Decimal;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<rust_decimal::Decimal> for Decimal {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Decimal(value)
+    }
+}
+
+impl From<Decimal> for rust_decimal::Decimal {
+    fn from(value: Decimal) -> Self {
+        value.0
+    }
+}
+
+impl From<rust_decimal::Decimal> for Interned<Decimal> {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Interned::from(Decimal(value))
+    }
+}
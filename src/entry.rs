@@ -0,0 +1,83 @@
+//! Home of [`InternEntry`], an entry-style API for the interner mirroring
+//! [`HashMap::entry`](std::collections::HashMap::entry).
+
+use crate::*;
+
+/// A view into the interner for a single value, which is either already interned
+/// ([`InternEntry::Occupied`]) or not ([`InternEntry::Vacant`]). Produced by
+/// [`Interned::entry`].
+///
+/// This makes it easy to run some logic only the first time a value is seen:
+///
+/// ```
+/// use interned::{InternEntry, Interned};
+///
+/// let mut first_seen = Vec::new();
+/// for word in ["a", "b", "a"] {
+///     if let InternEntry::Vacant(entry) = Interned::entry(word) {
+///         first_seen.push(entry.insert());
+///     }
+/// }
+/// assert_eq!(first_seen, [Interned::from("a"), Interned::from("b")]);
+/// ```
+pub enum InternEntry<T: Staticize>
+where
+    T::Static: Hash + Sized,
+{
+    /// An equal value is already interned, and this is its handle.
+    Occupied(Interned<T::Static>),
+    /// No equal value is interned yet.
+    Vacant(VacantInternEntry<T>),
+}
+
+/// A value that is not interned yet. See [`InternEntry`].
+pub struct VacantInternEntry<T> {
+    value: T,
+}
+
+impl<T: Hash + PartialEq + Copy + Staticize + DataType> Interned<T>
+where
+    <T as Staticize>::Static: Hash + Sized,
+{
+    /// Looks `value` up in the interner without interning it, returning an [`InternEntry`]
+    /// that can be used to inspect the existing handle or to intern `value` conditionally.
+    pub fn entry(value: T) -> InternEntry<T> {
+        match lookup(&value) {
+            Some(interned) => InternEntry::Occupied(interned),
+            None => InternEntry::Vacant(VacantInternEntry { value }),
+        }
+    }
+}
+
+impl<T: Hash + PartialEq + Copy + Staticize + DataType> InternEntry<T>
+where
+    <T as Staticize>::Static: Hash + Sized,
+{
+    /// Returns the existing handle, or interns the value if the entry is vacant.
+    pub fn or_insert(self) -> Interned<T::Static> {
+        match self {
+            InternEntry::Occupied(interned) => interned,
+            InternEntry::Vacant(entry) => entry.insert(),
+        }
+    }
+
+    /// Returns `true` if the value was already interned when this entry was created.
+    pub fn is_occupied(&self) -> bool {
+        matches!(self, InternEntry::Occupied(_))
+    }
+}
+
+impl<T: Hash + PartialEq + Copy + Staticize + DataType> VacantInternEntry<T>
+where
+    <T as Staticize>::Static: Hash + Sized,
+{
+    /// Returns a reference to the value that would be interned.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Interns the value, returning its new handle.
+    pub fn insert(self) -> Interned<T::Static> {
+        intern(self.value)
+    }
+}
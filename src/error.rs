@@ -0,0 +1,62 @@
+//! Home of [`InError`], an error type carrying an interned message.
+
+use crate::*;
+
+/// An error consisting solely of an interned message ([`InStr`]).
+///
+/// Since the message is interned, constructing the same error over and over (e.g. in a hot
+/// error path) only allocates the message once, and [`InError`] is [`Copy`] and cheap to
+/// compare.
+///
+/// ```
+/// use interned::InError;
+/// use std::error::Error;
+///
+/// fn parse(input: &str) -> Result<u32, Box<dyn Error>> {
+///     input.parse().map_err(|_| InError::from("not a number").into())
+/// }
+///
+/// assert_eq!(parse("7").unwrap(), 7);
+/// assert_eq!(parse("seven").unwrap_err().to_string(), "not a number");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InError(pub InStr);
+
+impl InError {
+    /// Returns the interned message of this error.
+    pub fn message(&self) -> InStr {
+        self.0
+    }
+}
+
+impl std::fmt::Debug for InError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("InError").field(&self.0.as_str()).finish()
+    }
+}
+
+impl Display for InError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for InError {}
+
+impl<'a> From<&'a str> for InError {
+    fn from(value: &'a str) -> Self {
+        InError(value.into())
+    }
+}
+
+impl From<String> for InError {
+    fn from(value: String) -> Self {
+        InError(value.into())
+    }
+}
+
+impl From<InStr> for InError {
+    fn from(value: InStr) -> Self {
+        InError(value)
+    }
+}
@@ -0,0 +1,79 @@
+//! Home of [`InternGrowthMonitor`], which helps applications detect runaway interning.
+
+use crate::*;
+use std::time::{Duration, Instant};
+
+/// Samples [`total_interned`] over time to estimate how quickly new values are being
+/// interned on the current thread.
+///
+/// A program that only ever interns a bounded set of values (identifiers, keywords, etc.) will
+/// see its growth rate fall to zero once warmed up. A rate that stays above zero indefinitely
+/// usually means an unbounded stream of unique values is being interned, which leaks memory
+/// (see the crate docs). Long-running applications can sample a monitor periodically and
+/// alert on a sustained nonzero rate. For a hard limit in tests, see
+/// [`assert_interned_bounded`].
+///
+/// ```
+/// use interned::{Interned, InternGrowthMonitor};
+///
+/// let mut monitor = InternGrowthMonitor::new();
+/// for i in 0..100u64 {
+///     let _ = Interned::<u64>::from(i);
+/// }
+/// monitor.sample();
+/// assert!(monitor.growth() >= 100);
+/// assert!(monitor.rate() > 0.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct InternGrowthMonitor {
+    initial: usize,
+    last_count: usize,
+    last_sampled: Instant,
+    rate: f64,
+}
+
+impl InternGrowthMonitor {
+    /// Creates a new [`InternGrowthMonitor`], taking an initial sample.
+    pub fn new() -> Self {
+        let count = total_interned();
+        InternGrowthMonitor {
+            initial: count,
+            last_count: count,
+            last_sampled: Instant::now(),
+            rate: 0.0,
+        }
+    }
+
+    /// Takes a new sample of [`total_interned`], updating and returning the growth rate
+    /// estimate (see [`InternGrowthMonitor::rate`]).
+    pub fn sample(&mut self) -> f64 {
+        let count = total_interned();
+        let now = Instant::now();
+        // guard against a zero-length interval when sampling in quick succession
+        let elapsed = now
+            .duration_since(self.last_sampled)
+            .max(Duration::from_nanos(1));
+        self.rate = count.saturating_sub(self.last_count) as f64 / elapsed.as_secs_f64();
+        self.last_count = count;
+        self.last_sampled = now;
+        self.rate
+    }
+
+    /// Returns the growth rate, in newly interned values per second, between the two most
+    /// recent samples. This is `0.0` until [`InternGrowthMonitor::sample`] is first called.
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Returns the number of values interned on the current thread between the creation of
+    /// this monitor and its most recent sample.
+    pub fn growth(&self) -> usize {
+        self.last_count.saturating_sub(self.initial)
+    }
+}
+
+impl Default for InternGrowthMonitor {
+    fn default() -> Self {
+        InternGrowthMonitor::new()
+    }
+}
@@ -0,0 +1,69 @@
+//! Home of [`InternedDyn`], an object-safe view of [`Interned`] values that allows interned
+//! values of different types to be stored and inspected side by side.
+
+use crate::*;
+use std::fmt::Debug;
+
+/// An object-safe trait implemented by every (debuggable) [`Interned`] value, allowing
+/// interned values of mixed types to be stored in type-erased collections such as
+/// `Vec<Box<dyn InternedDyn>>`.
+///
+/// ```
+/// use interned::{Interned, InternedDyn};
+///
+/// let values: Vec<Box<dyn InternedDyn>> = vec![
+///     Box::new(Interned::<i32>::from(7)),
+///     Box::new(Interned::<&str>::from("seven")),
+/// ];
+/// for value in &values {
+///     println!("{}: {:?}", value.type_name(), value);
+/// }
+/// ```
+pub trait InternedDyn {
+    /// Returns the raw heap pointer of the underlying interned value (see [`Interned::as_ptr`]).
+    fn as_ptr(&self) -> *const ();
+
+    /// Returns the hash code the underlying value was interned under.
+    fn hash_code(&self) -> u64;
+
+    /// Returns the name of the (static) type of the underlying interned value.
+    fn type_name(&self) -> &'static str;
+
+    /// Formats the underlying interned value using its [`Debug`] impl.
+    fn fmt_value(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
+}
+
+impl Debug for dyn InternedDyn + '_ {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_value(f)
+    }
+}
+
+impl<T: Hash + Staticize + DataType + Debug> InternedDyn for Interned<T>
+where
+    <T as DataType>::SliceValueType: Debug,
+{
+    fn as_ptr(&self) -> *const () {
+        Interned::as_ptr(self)
+    }
+
+    fn hash_code(&self) -> u64 {
+        self.value.hash_code()
+    }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<T::Static>()
+    }
+
+    fn fmt_value(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.value {
+            Static::Value(value) => unsafe { Debug::fmt(value.as_value::<T>(), f) },
+            Static::Slice(slice) => unsafe {
+                Debug::fmt(&slice.as_slice::<T::SliceValueType>(), f)
+            },
+            Static::Str(string) => Debug::fmt(string.as_str(), f),
+            Static::OsStr(os_str) => Debug::fmt(os_str.as_os_str(), f),
+            Static::Path(path) => Debug::fmt(path.as_path(), f),
+        }
+    }
+}
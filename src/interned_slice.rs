@@ -0,0 +1,61 @@
+//! Home of [`InternedSlice`], a trait for writing generic code over interned slices of any
+//! element type.
+
+use crate::*;
+
+/// Read-only slice operations shared by every interned slice, i.e. every
+/// [`Interned<T>`] where `T` is a slice type such as `&[i32]` or `&[&str]`.
+///
+/// This allows generic functions to accept any interned slice with a given element type:
+///
+/// ```
+/// use interned::{Interned, InternedSlice};
+///
+/// fn total(values: impl InternedSlice<Item = i32>) -> i32 {
+///     values.iter().sum()
+/// }
+///
+/// let values: Interned<&[i32]> = [1, 2, 3].as_slice().into();
+/// assert_eq!(total(values), 6);
+/// ```
+///
+/// Since interned slices live for the rest of the program, the returned references are
+/// `'static` and are not tied to the handle they were obtained from.
+pub trait InternedSlice {
+    /// The element type of the underlying slice.
+    type Item: 'static;
+
+    /// Returns the underlying interned slice.
+    fn as_slice(&self) -> &'static [Self::Item];
+
+    /// Returns the number of elements in the underlying slice.
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns `true` if the underlying slice has no elements.
+    fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    /// Returns the element at `index`, or [`None`] if `index` is out of bounds.
+    fn get_elem(&self, index: usize) -> Option<&'static Self::Item> {
+        self.as_slice().get(index)
+    }
+
+    /// Iterates over the elements of the underlying slice.
+    fn iter(&self) -> std::slice::Iter<'static, Self::Item> {
+        self.as_slice().iter()
+    }
+}
+
+impl<T: Hash + Staticize + DataType<Type = Slice>> InternedSlice for Interned<T>
+where
+    T::SliceValueType: Sized + 'static,
+{
+    type Item = T::SliceValueType;
+
+    fn as_slice(&self) -> &'static [Self::Item] {
+        self.interned_slice()
+    }
+}
@@ -0,0 +1,109 @@
+//! Contains [`InJson`], an interned JSON value. Enabled by the `serde_json` feature.
+//!
+//! [`serde_json::Value`] cannot be used with [`Interned`] directly because it owns heap data
+//! (and so is not [`Copy`]) and because neither [`Staticize`] nor [`serde_json::Value`] is
+//! local to this crate. Instead, [`InJson`] interns the _canonical serialized form_ of a value:
+//! compact JSON with object keys sorted at every level. Structurally equal values therefore
+//! dedupe to the same interned handle regardless of the key order they were built with.
+//!
+//! ```
+//! use interned::json::InJson;
+//! use serde_json::json;
+//!
+//! let a = InJson::from(json!({"name": "interned", "tags": ["a", "b"]}));
+//! let b = InJson::from(json!({"tags": ["a", "b"], "name": "interned"}));
+//! assert_eq!(a, b);
+//! assert_eq!(a.as_ptr(), b.as_ptr());
+//! assert_eq!(a.as_str(), r#"{"name":"interned","tags":["a","b"]}"#);
+//! assert_eq!(a.to_value()["name"], "interned");
+//! ```
+
+use crate::*;
+use serde_json::Value as JsonValue;
+
+/// An interned JSON value, stored as the [`Interned<&'static str>`] of its canonical
+/// serialized form (see the [module docs](self)).
+///
+/// Like [`InStr`], an [`InJson`] is a plain [`Copy`] handle, and equality and hashing are
+/// those of the canonical text.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct InJson(Interned<&'static str>);
+
+impl InJson {
+    /// Returns the canonical serialized form of this JSON value.
+    pub fn as_str(&self) -> &'static str {
+        self.0.interned_str()
+    }
+
+    /// Returns the underlying heap pointer where the canonical form of this value is stored.
+    pub fn as_ptr(&self) -> *const () {
+        self.0.as_ptr()
+    }
+
+    /// Returns the underlying [`Interned`] string holding the canonical form of this value.
+    pub fn interned(&self) -> Interned<&'static str> {
+        self.0
+    }
+
+    /// Parses the canonical form of this value back into an owned [`serde_json::Value`].
+    pub fn to_value(&self) -> JsonValue {
+        serde_json::from_str(self.as_str()).expect("canonical JSON is always valid")
+    }
+}
+
+/// Writes `value` to `out` as compact JSON with object keys sorted at every level.
+fn write_canonical(value: &JsonValue, out: &mut String) {
+    match value {
+        JsonValue::Array(values) => {
+            out.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(value, out);
+            }
+            out.push(']');
+        }
+        JsonValue::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&JsonValue::from(key.as_str()).to_string());
+                out.push(':');
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+impl<'a> From<&'a JsonValue> for InJson {
+    fn from(value: &'a JsonValue) -> Self {
+        let mut canonical = String::new();
+        write_canonical(value, &mut canonical);
+        InJson(Interned::from(canonical.as_str()))
+    }
+}
+
+impl From<JsonValue> for InJson {
+    fn from(value: JsonValue) -> Self {
+        InJson::from(&value)
+    }
+}
+
+impl From<InJson> for JsonValue {
+    fn from(value: InJson) -> Self {
+        value.to_value()
+    }
+}
+
+impl Display for InJson {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
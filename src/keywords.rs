@@ -0,0 +1,123 @@
+//! Home of the [`keywords!`](macro@crate::keywords) macro, which generates an enum of fixed,
+//! well-known strings (such as the keywords of a language) whose interned handles are cached
+//! so that repeated lookups avoid any hashing.
+
+/// Generates an enum over a fixed set of keyword strings, along with cached [`Interned`]
+/// handles for each keyword.
+///
+/// The generated enum derives [`Copy`], [`Clone`], [`PartialEq`], [`Eq`], [`PartialOrd`],
+/// [`Ord`], [`Hash`], and [`Debug`], and provides:
+/// - `ALL`, a `const` slice of every variant in declaration order,
+/// - `as_str(self) -> &'static str`, returning the keyword string (a `const fn`),
+/// - `intern(self) -> Interned<&'static str>`, returning the interned keyword. All keywords
+///   are interned together on first use (per thread) and cached in a `thread_local`
+///   [`OnceCell`](std::cell::OnceCell), so subsequent calls are a simple indexed load,
+/// - `from_str(&str) -> Option<Self>`, a reverse lookup from a keyword string,
+/// - [`Display`](std::fmt::Display) and [`From`] impls into [`Interned<&'static str>`] and
+///   `&'static str`.
+///
+/// ```
+/// use interned::*;
+///
+/// keywords! {
+///     pub enum Keyword {
+///         Let = "let",
+///         If = "if",
+///     }
+/// }
+///
+/// assert_eq!(Keyword::Let.intern(), Interned::from("let"));
+/// assert_eq!(Keyword::If.as_str(), "if");
+/// assert_eq!(Keyword::from_str("if"), Some(Keyword::If));
+/// assert_eq!(Keyword::from_str("else"), None);
+/// ```
+///
+/// If the enum header is omitted, a `pub enum Keyword` is generated:
+///
+/// ```
+/// use interned::*;
+///
+/// keywords! { Let = "let", If = "if" }
+///
+/// assert_eq!(Keyword::If.intern().interned_str(), "if");
+/// ```
+///
+/// [`Interned`]: crate::Interned
+/// [`Interned<&'static str>`]: crate::Interned
+#[macro_export]
+macro_rules! keywords {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $name:ident {
+            $($variant:ident = $value:literal),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        $vis enum $name {
+            $($variant),*
+        }
+
+        impl $name {
+            /// Every variant of this keyword enum, in declaration order.
+            pub const ALL: &'static [$name] = &[$($name::$variant),*];
+
+            /// Returns the keyword string for this variant.
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    $($name::$variant => $value),*
+                }
+            }
+
+            /// Returns the cached interned handle for this keyword.
+            pub fn intern(self) -> $crate::Interned<&'static str> {
+                ::std::thread_local! {
+                    static CACHE: ::std::cell::OnceCell<::std::vec::Vec<$crate::Interned<&'static str>>> =
+                        const { ::std::cell::OnceCell::new() };
+                }
+                CACHE.with(|cache| {
+                    cache.get_or_init(|| {
+                        $name::ALL
+                            .iter()
+                            .map(|keyword| $crate::Interned::from(keyword.as_str()))
+                            .collect()
+                    })[self as usize]
+                })
+            }
+
+            /// Looks up the variant whose keyword string is exactly `value`, if any.
+            #[allow(clippy::should_implement_trait)]
+            pub fn from_str(value: &str) -> ::std::option::Option<Self> {
+                match value {
+                    $($value => ::std::option::Option::Some($name::$variant),)*
+                    _ => ::std::option::Option::None,
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::std::convert::From<$name> for $crate::Interned<&'static str> {
+            fn from(value: $name) -> Self {
+                value.intern()
+            }
+        }
+
+        impl ::std::convert::From<$name> for &'static str {
+            fn from(value: $name) -> Self {
+                value.as_str()
+            }
+        }
+    };
+    ($($variant:ident = $value:literal),* $(,)?) => {
+        $crate::keywords! {
+            pub enum Keyword {
+                $($variant = $value),*
+            }
+        }
+    };
+}
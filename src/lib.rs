@@ -30,6 +30,25 @@
 //! integer types directly, however it makes sense to do so for the purposes of memoizing an
 //! expensive computation via [`Memoized<I, T>`].
 //!
+//! ### Pass-Through Mode
+//!
+//! Building with `RUSTFLAGS="--cfg interned_no_intern"` turns interning into a pass-through,
+//! which is useful for measuring what interning actually buys a particular program (e.g. A/B
+//! benchmarking) without changing any code. With `interned_no_intern` set:
+//!
+//! - **every** conversion into an [`Interned<T>`] leaks a fresh heap copy of the value, so
+//!   memory use grows with every call rather than with every _unique_ value,
+//! - equal values no longer share a heap address, so [`Interned::as_ptr`],
+//!   [`Interned::token`], and [`ByPtr`] can no longer be used to identify values,
+//! - equality falls back to comparing values by content, which is no longer `O(1)`, and
+//! - nothing is recorded in the thread-local interner, so introspection functions such as
+//!   [`num_interned`] report nothing.
+//!
+//! This mode is intended for benchmarking only and should never be enabled in production.
+//! Since it changes behavior rather than adding functionality, it is deliberately a `--cfg`
+//! flag rather than a Cargo feature: only the final build can opt into it, so no dependency
+//! can switch it on behind the back of code that relies on pointer identity.
+//!
 //! An interned string type, [`InStr`], is also provided as a convenient wrapper around
 //! `Interned<&'static str>`. It has a number of extra impls and should be your go-to type if
 //! you want to work with interned strings.
@@ -46,7 +65,7 @@
 //! assert_eq!(a.as_ptr(), b.as_ptr());
 //! assert_ne!(b.as_ptr(), c.as_ptr());
 //! let d: Interned<&str> = "asdf".into();
-//! assert_ne!(d, "fdsa".into());
+//! assert_ne!(d, Interned::from("fdsa"));
 //! assert_eq!(Interned::from("asdf"), d);
 //! let e = Interned::from([1, 2, 3, 4, 5].as_slice());
 //! let f = InStr::from("abc");
@@ -101,12 +120,52 @@
 docify::compile_markdown!("README.docify.md", "README.md");
 
 pub mod _unsafe;
+pub mod arena;
+pub use arena::ArenaInterned;
+pub mod by_ptr;
+pub use by_ptr::ByPtr;
 pub mod datatype;
 pub use datatype::DataType;
+pub mod growth;
+pub use growth::InternGrowthMonitor;
+#[cfg(feature = "chrono")]
+pub mod datetime;
+#[cfg(feature = "rust_decimal")]
+pub mod decimal;
+pub mod entry;
+pub use entry::{InternEntry, VacantInternEntry};
+pub mod error;
+pub use error::InError;
+pub mod interned_dyn;
+pub use interned_dyn::InternedDyn;
+pub mod interned_slice;
+pub use interned_slice::InternedSlice;
+#[cfg(feature = "serde_json")]
+pub mod json;
+pub mod keywords;
+pub mod lowercase;
+pub use lowercase::LowercaseInterned;
 pub mod memoized;
 pub use memoized::Memoized;
+#[cfg(feature = "sha2")]
+pub mod merkle;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+mod path_ops;
+pub mod rate_limit;
+pub use rate_limit::{RateLimited, RateLimitedInterner};
+pub mod shared;
+#[cfg(feature = "rayon")]
+pub use shared::par_intern_many;
+pub use shared::SharedInterned;
+#[cfg(feature = "rkyv")]
+mod rkyv_impls;
+mod slice_ops;
+mod str_ops;
 pub mod unsized_types;
 pub use unsized_types::*;
+#[cfg(feature = "uuid")]
+pub mod uuids;
 
 use _unsafe::*;
 use datatype::*;
@@ -114,41 +173,73 @@ use staticize::*;
 
 use std::{
     any::TypeId,
+    borrow::Cow,
     cell::RefCell,
     collections::{
         hash_map::{DefaultHasher, Entry},
         HashMap,
     },
-    ffi::OsStr,
+    ffi::{OsStr, OsString},
     fmt::Display,
     hash::{BuildHasher, Hash, Hasher},
     marker::PhantomData,
     ops::Deref,
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 thread_local! {
     /// Internal thread-local data structure used to store all interned values.
-    static INTERNED: RefCell<HashMap<TypeId, HashMap<u64, Static>, TypeIdHasherBuilder>> = RefCell::new(HashMap::with_hasher(TypeIdHasherBuilder));
+    static INTERNED: RefCell<HashMap<TypeId, HashMap<u64, Static, NoHashU64>, TypeIdHasherBuilder>> = const { RefCell::new(HashMap::with_hasher(TypeIdHasherBuilder)) };
 
     /// Internal thread-local data structure used to store all memoized values.
-    static MEMOIZED: RefCell<HashMap<TypeId, HashMap<u64, Static>, TypeIdHasherBuilder>> = RefCell::new(HashMap::with_hasher(TypeIdHasherBuilder));
+    static MEMOIZED: RefCell<HashMap<TypeId, HashMap<u64, Static, NoHashU64>, TypeIdHasherBuilder>> = const { RefCell::new(HashMap::with_hasher(TypeIdHasherBuilder)) };
+
+    /// Internal thread-local index of the [`MEMOIZED`] keys inserted under each scope (keyed
+    /// by the hash of the scope and the memoized type) via [`Memoized::from_drainable`], so
+    /// that a whole scope can be drained at once (see [`drain_scope`]). A scope's keys are
+    /// removed from the index when it is drained.
+    static MEMOIZED_SCOPES: RefCell<HashMap<u64, Vec<u64>, NoHashU64>> = const { RefCell::new(HashMap::with_hasher(NoHashU64)) };
+
+    /// Internal thread-local table of the interned `u8`s, indexed by value. Each slot is filled
+    /// (and its value interned in [`INTERNED`]) the first time that value is interned on the
+    /// current thread, after which interning it is a simple indexed load with no hashing or
+    /// allocation.
+    static INTERNED_U8: RefCell<[Option<Static>; 256]> = const { RefCell::new([None; 256]) };
+
+    /// Internal thread-local table of the interned single-byte `&[u8]`s, indexed by byte
+    /// value. Like [`INTERNED_U8`], each slot is filled the first time it is needed.
+    static INTERNED_BYTE_SLICES: RefCell<[Option<Static>; 256]> = const { RefCell::new([None; 256]) };
+
+    /// Internal thread-local record of the name of each type interned in [`INTERNED`], since
+    /// its keys are bare [`TypeId`]s. Recorded when the first value of a type is interned.
+    static INTERNED_TYPE_NAMES: RefCell<HashMap<TypeId, &'static str, TypeIdHasherBuilder>> = const { RefCell::new(HashMap::with_hasher(TypeIdHasherBuilder)) };
 }
 
-/// Internal [`Hasher`] used to hash a [`TypeId`] by simply using the underlying `u64` of the
-/// [`TypeId`] as the hash code. This results in a zero-cost hash operation for [`TypeId`].
+/// Internal [`Hasher`] used to hash a [`TypeId`] by simply using the bits of the [`TypeId`]
+/// itself as the hash code. [`TypeId`]s are already the output of a high quality hash, so this
+/// results in a (nearly) zero-cost hash operation for [`TypeId`].
 struct TypeIdHasher {
-    hash: Option<u64>,
+    hash: u64,
 }
 
 impl Hasher for TypeIdHasher {
     fn finish(&self) -> u64 {
-        self.hash.unwrap()
+        self.hash
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.hash ^= i;
     }
 
     fn write(&mut self, bytes: &[u8]) {
-        debug_assert!(bytes.len() == 8);
-        self.hash = Some(bytes.as_ptr() as u64);
+        // the size of the data `TypeId` feeds to its `Hash` impl is an implementation detail
+        // that has changed before (`TypeId` itself grew from 64 to 128 bits), so rather than
+        // assuming 8 bytes, fold however many bytes are written into the hash in 8-byte words
+        for word in bytes.chunks(8) {
+            let mut buf = [0u8; 8];
+            buf[..word.len()].copy_from_slice(word);
+            self.hash ^= u64::from_ne_bytes(buf);
+        }
     }
 }
 
@@ -159,7 +250,43 @@ impl BuildHasher for TypeIdHasherBuilder {
     type Hasher = TypeIdHasher;
 
     fn build_hasher(&self) -> Self::Hasher {
-        TypeIdHasher { hash: None }
+        TypeIdHasher { hash: 0 }
+    }
+}
+
+/// Internal [`Hasher`] used to hash the `u64` content hashes that key the inner per-type maps
+/// by simply using the `u64` itself as the hash code. These keys are already the output of a
+/// full hash function, so hashing them again would be redundant.
+struct NoHashU64Hasher {
+    hash: u64,
+}
+
+impl Hasher for NoHashU64Hasher {
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.hash = i;
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        // only `u64` keys are ever hashed, which always go through `write_u64`
+        for byte in bytes {
+            self.hash = self.hash.rotate_left(8) ^ *byte as u64;
+        }
+    }
+}
+
+/// Internal [`BuildHasher`] used to set up [`NoHashU64Hasher`] in a usable form.
+#[derive(Copy, Clone, Default)]
+struct NoHashU64;
+
+impl BuildHasher for NoHashU64 {
+    type Hasher = NoHashU64Hasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        NoHashU64Hasher { hash: 0 }
     }
 }
 
@@ -172,8 +299,7 @@ impl BuildHasher for TypeIdHasherBuilder {
 #[derive(Copy, Clone)]
 pub struct Interned<T: Hash> {
     _value: PhantomData<T>,
-    #[doc(hidden)]
-    pub value: Static,
+    value: Static,
 }
 
 impl<T: Hash> Interned<T> {
@@ -182,48 +308,276 @@ impl<T: Hash> Interned<T> {
     pub fn as_ptr(&self) -> *const () {
         self.value.as_ptr()
     }
+
+    /// Returns a compact `u64` token identifying this interned value, namely its heap address
+    /// (see [`Interned::as_ptr`]). Equal values interned on the same thread always have the
+    /// same token, making tokens handy as keys in side tables. Like the address itself, a
+    /// token is only meaningful on the current thread and for the current run.
+    ///
+    /// Note that slices of zero-sized elements (including empty slices) are never allocated,
+    /// so such slices of different lengths share an address and therefore a token. Compare
+    /// [`Interned`] values with `==` rather than by token when that matters.
+    pub fn token(&self) -> u64 {
+        self.as_ptr() as usize as u64
+    }
+
+    /// Compares two [`Interned`] values by their heap address rather than by value.
+    ///
+    /// This is a cheap total order that is stable for the life of the program (interned values
+    /// never move), making it useful for canonicalizing iteration order within a single run.
+    /// It is _not_ deterministic across runs, since heap addresses will differ, and it has no
+    /// relationship to the ordering of the underlying values.
+    pub fn ptr_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_ptr().cmp(&other.as_ptr())
+    }
 }
 
-impl<T: Hash + Copy + Staticize + DataType> From<Static> for Interned<T> {
-    fn from(value: Static) -> Self {
+impl<T: Hash + Staticize> Interned<T> {
+    /// Returns the type-erased [`Static`] handle backing this [`Interned`] value.
+    ///
+    /// The returned [`Static`] can be turned back into an [`Interned`] via
+    /// [`Interned::from_static_checked`].
+    pub(crate) fn as_static(&self) -> Static {
+        self.value
+    }
+
+    /// Reconstructs an [`Interned<T>`] from a type-erased [`Static`] handle, returning `None`
+    /// unless `value` is the very handle the thread-local interner holds for type `T`.
+    ///
+    /// Because the check is performed against the interner's per-[`TypeId`] storage, a
+    /// [`Static`] that was interned as some other type (or never interned at all) is rejected
+    /// rather than producing a type-mismatched handle.
+    ///
+    /// ```
+    /// use interned::{Interned, _unsafe::Static};
+    ///
+    /// let _ = Interned::<i32>::from(77);
+    /// // an equal value that was never interned is not a handle held by the interner
+    /// let stray = Static::from_value(77i32, None);
+    /// assert_eq!(Interned::<i32>::from_static_checked(stray), None);
+    /// ```
+    pub fn from_static_checked(value: Static) -> Option<Self> {
         let type_id = T::static_type_id();
-        let entry = INTERNED.with(|interned| {
-            *interned
-                .borrow_mut()
-                .entry(type_id)
-                .or_insert_with(|| HashMap::new())
-                .entry(value.hash_code())
-                .or_insert(value)
+        let found = INTERNED.with(|interned| {
+            let interned = interned.borrow();
+            let Some(values) = interned.get(&type_id) else {
+                return false;
+            };
+            // colliding values are stored at subsequent keys, see `intern_with`
+            let mut key = value.hash_code();
+            while let Some(entry) = values.get(&key) {
+                if entry.as_ptr() == value.as_ptr() {
+                    return true;
+                }
+                key = key.wrapping_add(1);
+            }
+            false
         });
-        Interned {
+        found.then_some(Interned {
             _value: PhantomData,
-            value: entry,
-        }
+            value,
+        })
     }
 }
 
-impl<T: Hash + Copy + Staticize + DataType + From<Interned<T>>> From<T> for Interned<T::Static>
+impl<T: Hash + PartialEq + Copy + Staticize + DataType + From<Interned<T>>> From<T>
+    for Interned<T::Static>
 where
     <T as Staticize>::Static: Hash + Sized,
 {
     fn from(value: T) -> Interned<T::Static> {
-        let mut hasher = DefaultHasher::default();
-        value.hash(&mut hasher);
-        let hash = hasher.finish();
-        let type_id = T::static_type_id();
-        let entry = INTERNED.with(|interned| {
-            *interned
-                .borrow_mut()
-                .entry(type_id)
-                .or_insert_with(|| HashMap::new())
-                .entry(hash)
-                .or_insert_with(|| value.to_static_with_hash(Some(hash)))
-        });
-        Interned {
+        intern(value)
+    }
+}
+
+impl<T: Hash + PartialEq + Copy + Staticize + DataType> Interned<T>
+where
+    <T as Staticize>::Static: Hash + Sized,
+{
+    /// Interns `value` just like [`Interned::from`], additionally returning `true` if this
+    /// call added `value` to the interner, or `false` if an equal value was already interned.
+    ///
+    /// This takes a single trip through the interner, so it is cheaper than checking for an
+    /// existing value first and then interning it.
+    ///
+    /// ```
+    /// use interned::Interned;
+    ///
+    /// let (a, inserted) = Interned::from_reporting("fresh");
+    /// assert!(inserted);
+    /// let (b, inserted) = Interned::from_reporting("fresh");
+    /// assert!(!inserted);
+    /// assert_eq!(a.as_ptr(), b.as_ptr());
+    /// ```
+    pub fn from_reporting(value: T) -> (Interned<T::Static>, bool) {
+        intern_reporting(value)
+    }
+}
+
+/// Interns the specified `value`, returning an [`Interned`] for the `'static` version of its
+/// type. This is the machinery behind [`From<T>`] for [`Interned`], minus the
+/// [`From<Interned<T>>`] bound, which makes it usable from generic helpers within this crate.
+pub(crate) fn intern<T: Hash + PartialEq + Copy + Staticize + DataType>(
+    value: T,
+) -> Interned<T::Static>
+where
+    <T as Staticize>::Static: Hash + Sized,
+{
+    intern_reporting(value).0
+}
+
+/// Like [`intern`], but also returns whether `value` was newly added to the interner by this
+/// call (as opposed to an equal value having already been interned).
+fn intern_reporting<T: Hash + PartialEq + Copy + Staticize + DataType>(
+    value: T,
+) -> (Interned<T::Static>, bool)
+where
+    <T as Staticize>::Static: Hash + Sized,
+{
+    if cfg!(interned_no_intern) {
+        // pass-through mode: leak a fresh copy every time, bypassing all de-duplication
+        let interned = Interned {
             _value: PhantomData,
-            value: entry,
+            value: value.to_static(),
+        };
+        return (interned, true);
+    }
+    if T::static_type_id() == TypeId::of::<u8>() && std::mem::size_of::<T>() == 1 {
+        // `T` is `u8` (which has no lifetimes to erase), so reading it as a `u8` is sound
+        let byte = unsafe { *(&value as *const T as *const u8) };
+        return INTERNED_U8.with(|table| {
+            let slot = table.borrow()[byte as usize];
+            match slot {
+                Some(entry) => {
+                    let interned = Interned {
+                        _value: PhantomData,
+                        value: entry,
+                    };
+                    (interned, false)
+                }
+                None => {
+                    // an empty slot means this byte hasn't been interned through the table
+                    // yet, so intern it in `INTERNED` now, which reports whether it is new
+                    let (interned, inserted) =
+                        intern_with(value, |hash| value.to_static_with_hash(Some(hash)));
+                    table.borrow_mut()[byte as usize] = Some(interned.value);
+                    (interned, inserted)
+                }
+            }
+        });
+    }
+    if T::static_type_id() == TypeId::of::<bool>() && std::mem::size_of::<T>() == 1 {
+        // `T` is `bool`, so reading it as a `bool` is sound. Both values are backed by
+        // canonical statics, so interning a `bool` never leaks an allocation.
+        let canonical = match unsafe { *(&value as *const T as *const bool) } {
+            true => &CANONICAL_TRUE,
+            false => &CANONICAL_FALSE,
+        };
+        return intern_with(value, |hash| Static::from_static_ref(canonical, Some(hash)));
+    }
+    if T::static_type_id() == TypeId::of::<()>() && std::mem::size_of::<T>() == 0 {
+        return intern_with(value, |hash| {
+            Static::from_static_ref(&CANONICAL_UNIT, Some(hash))
+        });
+    }
+    intern_with(value, |hash| value.to_static_with_hash(Some(hash)))
+}
+
+/// Canonical backing storage for interned `bool` and `()` values.
+static CANONICAL_TRUE: bool = true;
+static CANONICAL_FALSE: bool = false;
+static CANONICAL_UNIT: () = ();
+
+/// Returns the [`Interned`] for `value` if an equal value has already been interned on the
+/// current thread, without interning it otherwise.
+pub(crate) fn lookup<T: Hash + PartialEq + Staticize + DataType>(
+    value: &T,
+) -> Option<Interned<T::Static>>
+where
+    <T as Staticize>::Static: Hash + Sized,
+{
+    if T::static_type_id() == TypeId::of::<u8>() && std::mem::size_of::<T>() == 1 {
+        // consult the same table as `intern_reporting`, so that a byte is vacant here exactly
+        // when interning it would report a fresh insertion
+        let byte = unsafe { *(value as *const T as *const u8) };
+        if let Some(entry) = INTERNED_U8.with(|table| table.borrow()[byte as usize]) {
+            return Some(Interned {
+                _value: PhantomData,
+                value: entry,
+            });
         }
     }
+    let mut hasher = DefaultHasher::default();
+    value.hash(&mut hasher);
+    let hash = hasher.finish();
+    let type_id = T::static_type_id();
+    let entry = INTERNED.with(|interned| {
+        let interned = interned.borrow();
+        let values = interned.get(&type_id)?;
+        let mut key = hash;
+        // follow the same probe sequence as `intern_with`
+        while let Some(entry) = values.get(&key) {
+            if unsafe { entry._eq_value(value) } {
+                return Some(*entry);
+            }
+            key = key.wrapping_add(1);
+        }
+        None
+    })?;
+    Some(Interned {
+        _value: PhantomData,
+        value: entry,
+    })
+}
+
+/// Interns the specified `value` via the main thread-local interning map, using `to_static`
+/// to produce the backing [`Static`] (from the hash of `value`) if it isn't already interned.
+/// Also returns whether `value` was newly inserted.
+fn intern_with<T: Hash + PartialEq + Staticize + DataType>(
+    value: T,
+    to_static: impl FnOnce(u64) -> Static,
+) -> (Interned<T::Static>, bool)
+where
+    <T as Staticize>::Static: Hash + Sized,
+{
+    let mut hasher = DefaultHasher::default();
+    value.hash(&mut hasher);
+    let hash = hasher.finish();
+    let type_id = T::static_type_id();
+    let (entry, inserted) = INTERNED.with(|interned| {
+        let mut interned = interned.borrow_mut();
+        let values = interned.entry(type_id).or_default();
+        // values are keyed by hash code, so on a hash collision with a _different_ value we
+        // linearly probe for the next free key rather than handing back the wrong value
+        let mut key = hash;
+        loop {
+            match values.entry(key) {
+                Entry::Occupied(entry) if unsafe { entry.get()._eq_value(&value) } => {
+                    break (*entry.get(), false)
+                }
+                Entry::Occupied(_) => key = key.wrapping_add(1),
+                Entry::Vacant(entry) => break (*entry.insert(to_static(hash)), true),
+            }
+        }
+    });
+    if inserted {
+        register_type_name::<T>();
+    }
+    let interned = Interned {
+        _value: PhantomData,
+        value: entry,
+    };
+    (interned, inserted)
+}
+
+/// Records the name of `T` in [`INTERNED_TYPE_NAMES`], if it isn't recorded already.
+fn register_type_name<T: Staticize>() {
+    INTERNED_TYPE_NAMES.with(|names| {
+        names
+            .borrow_mut()
+            .entry(T::static_type_id())
+            .or_insert_with(std::any::type_name::<T::Static>);
+    });
 }
 
 impl<T: Hash + Staticize + DataType<Type = Slice>> Interned<T> {
@@ -309,19 +663,33 @@ where
 
 impl<T: Hash + Staticize + PartialOrd + DataType> PartialOrd for Interned<T>
 where
-    <T as DataType>::SliceValueType: PartialEq,
+    <T as DataType>::SliceValueType: PartialOrd,
 {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         unsafe { self.value._partial_cmp::<T>(&other.value) }
     }
 }
 
+impl<T: Hash + Staticize + Ord + DataType> Interned<T>
+where
+    <T as DataType>::SliceValueType: Ord,
+{
+    /// Compares two interned values by their underlying values (element-wise for slices),
+    /// never by address or hash code. This is the total order used by the [`Ord`] impl, so
+    /// it agrees with [`Ord`] for the value types themselves, and a
+    /// [`BTreeMap<Interned<T>, V>`](std::collections::BTreeMap) iterates in the same order
+    /// as a `BTreeMap<T, V>` would, regardless of interning order.
+    pub fn value_ord(&self, other: &Self) -> std::cmp::Ordering {
+        unsafe { self.value._cmp::<T>(&other.value) }
+    }
+}
+
 impl<T: Hash + Staticize + Ord + DataType> Ord for Interned<T>
 where
-    <T as DataType>::SliceValueType: PartialEq,
+    <T as DataType>::SliceValueType: Ord,
 {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        unsafe { self.value._cmp::<T>(&other.value) }
+        self.value_ord(other)
     }
 }
 
@@ -331,14 +699,26 @@ impl<T: Hash + Staticize> Hash for Interned<T> {
     }
 }
 
+/// When formatted with the alternate [`Debug`](std::fmt::Debug) flag (`{:#?}`), interned
+/// slices longer than this many elements print `[..N bytes..]` along with the hash code of the
+/// slice instead of their full contents, which keeps logs of large interned buffers readable.
+/// Plain `{:?}` always prints the full contents.
+pub const DEBUG_SLICE_LIMIT: usize = 64;
+
 impl<T: Hash + Staticize + DataType + std::fmt::Debug> std::fmt::Debug for Interned<T>
 where
     <T as DataType>::SliceValueType: std::fmt::Debug,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let alternate = f.alternate();
         let mut f = f.debug_struct(format!("Interned<{}>", T::static_type_name()).as_str());
         let ret = match self.value {
             Static::Value(value) => f.field("value", unsafe { value.as_value::<T>() }),
+            Static::Slice(slice) if alternate && slice.len() > DEBUG_SLICE_LIMIT => {
+                let bytes = slice.len() * std::mem::size_of::<T::SliceValueType>();
+                f.field("slice", &format_args!("[..{bytes} bytes..]"))
+                    .field("hash", &self.value.hash_code())
+            }
             Static::Slice(slice) => {
                 f.field("slice", unsafe { &slice.as_slice::<T::SliceValueType>() })
             }
@@ -378,17 +758,272 @@ pub fn num_interned<T: Staticize>() -> usize {
     INTERNED.with(|interned| interned.borrow_mut().entry(type_id).or_default().len())
 }
 
+/// Returns a snapshot of every [`Static`] currently interned on the current thread for the
+/// specified type `T`, in no particular order.
+///
+/// The snapshot is taken up front so that callers are free to intern (and thus borrow the
+/// interner) while walking it.
+fn interned_statics<T: Staticize>() -> Vec<Static> {
+    let type_id = T::static_type_id();
+    INTERNED.with(|interned| {
+        interned
+            .borrow()
+            .get(&type_id)
+            .map(|values| values.values().copied().collect())
+            .unwrap_or_default()
+    })
+}
+
+/// An iterator over every value of type `T` interned on the current thread, in no particular
+/// order. Produced by [`with_interned`], which scopes the iterator to a borrow of the
+/// thread-local interner.
+pub struct InternedIter<'a, T> {
+    values: Option<std::collections::hash_map::Values<'a, u64, Static>>,
+    _value: PhantomData<T>,
+}
+
+impl<T: Staticize> Iterator for InternedIter<'_, T>
+where
+    <T as Staticize>::Static: Hash + Sized,
+{
+    type Item = Interned<T::Static>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = *self.values.as_mut()?.next()?;
+        Some(Interned {
+            _value: PhantomData,
+            value,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values
+            .as_ref()
+            .map_or((0, Some(0)), |values| values.size_hint())
+    }
+}
+
+impl<T: Staticize> ExactSizeIterator for InternedIter<'_, T> where
+    <T as Staticize>::Static: Hash + Sized
+{
+}
+
+/// Calls `f` with an [`InternedIter`] over every value of type `T` interned on the current
+/// thread, returning the result of `f`.
+///
+/// Unlike introspection functions that copy handles out into a [`Vec`], this borrows the
+/// interner in place for the duration of `f`, so iterating does not allocate. Because the
+/// interner is borrowed, interning (or memoizing) anything from within `f` will panic.
+///
+/// ```
+/// use interned::*;
+///
+/// let _ = Interned::<i32>::from(-5);
+/// let negatives = with_interned::<i32, _>(|values| values.filter(|v| **v < 0).count());
+/// assert!(negatives >= 1);
+/// ```
+pub fn with_interned<T: Staticize, R>(f: impl FnOnce(InternedIter<'_, T>) -> R) -> R
+where
+    <T as Staticize>::Static: Hash + Sized,
+{
+    let type_id = T::static_type_id();
+    INTERNED.with(|interned| {
+        let interned = interned.borrow();
+        f(InternedIter {
+            values: interned.get(&type_id).map(|values| values.values()),
+            _value: PhantomData,
+        })
+    })
+}
+
+/// Returns the number of distinct values of type `T` currently interned on the current thread
+/// for which `pred` returns `true`. This is useful for introspection, such as building
+/// histograms of the interned pool.
+///
+/// For interned strings, see [`count_interned_str_where`].
+pub fn count_interned_where<T: Staticize + DataType<Type = Value>>(
+    pred: impl Fn(&T) -> bool,
+) -> usize {
+    interned_statics::<T>()
+        .into_iter()
+        .filter(|value| pred(unsafe { value.as_value::<T>() }))
+        .count()
+}
+
+/// Returns the number of distinct strings currently interned on the current thread for which
+/// `pred` returns `true`. See [`count_interned_where`].
+pub fn count_interned_str_where(pred: impl Fn(&str) -> bool) -> usize {
+    interned_statics::<&str>()
+        .into_iter()
+        .filter(|value| pred(value.as_str()))
+        .count()
+}
+
+/// Returns the total number of values currently interned by [`Interned`] on the current
+/// thread, across all types.
+pub fn total_interned() -> usize {
+    INTERNED.with(|interned| interned.borrow().values().map(HashMap::len).sum())
+}
+
+/// Returns the name and number of interned values of every type interned on the current
+/// thread, sorted by name, e.g. `[("&str", 12), ("u64", 3)]`. Useful for a quick diagnostic
+/// dump of what the interner is holding on to.
+pub fn interned_type_summary() -> Vec<(&'static str, usize)> {
+    let mut summary: Vec<(&'static str, usize)> = INTERNED.with(|interned| {
+        INTERNED_TYPE_NAMES.with(|names| {
+            let names = names.borrow();
+            interned
+                .borrow()
+                .iter()
+                .filter(|(_, values)| !values.is_empty())
+                .map(|(type_id, values)| {
+                    let name = names.get(type_id).copied().unwrap_or("<unknown>");
+                    (name, values.len())
+                })
+                .collect()
+        })
+    });
+    summary.sort_unstable();
+    summary
+}
+
+/// Returns `true` if `ptr` is the address of a value interned on the current thread (of any
+/// type), as returned by [`Interned::as_ptr`].
+///
+/// This is handy in debug assertions, e.g. to check that a `&'static str` handed to you
+/// really came from the interner. Only exact addresses match, so a pointer into the middle of
+/// an interned value returns `false`. This scans every interned value, so it is _O(n)_ in the
+/// number of values interned on the current thread.
+pub fn is_interned_ptr(ptr: *const ()) -> bool {
+    INTERNED.with(|interned| {
+        interned
+            .borrow()
+            .values()
+            .flat_map(HashMap::values)
+            .any(|value| value.as_ptr() == ptr)
+    })
+}
+
+/// Returns the heap address (as per [`Interned::as_ptr`]) and size in bytes of every value of
+/// type `T` interned on the current thread, in no particular order, so that profilers and
+/// other external tooling can map interned addresses to allocation sizes.
+///
+/// Sizes are those of the interned data itself: `size_of::<T>()` for plain values, the
+/// element size times the length for slices, and the length in bytes for `&str`, `&OsStr`,
+/// and `&Path`. Empty slices and strings (and zero-sized values) report a size of `0`.
+pub fn interned_layout_report<T: Staticize + DataType>() -> Vec<(*const (), usize)> {
+    interned_statics::<T>()
+        .into_iter()
+        .map(|value| {
+            let size = match value {
+                Static::Value(_) => std::mem::size_of::<T::ValueType>(),
+                Static::Slice(slice) => slice.len() * std::mem::size_of::<T::SliceValueType>(),
+                Static::Str(_) => value.as_str().len(),
+                Static::OsStr(_) => value.as_os_str().len(),
+                Static::Path(_) => value.as_path().as_os_str().len(),
+            };
+            (value.as_ptr(), size)
+        })
+        .collect()
+}
+
+/// Panics if more than `max` values of type `T` are currently interned on the current thread.
+///
+/// Since interned values are never freed, interning an unbounded stream of unique values
+/// leaks memory without bound. Sprinkling this into tests and long-running loops catches that
+/// mistake early. See also [`InternGrowthMonitor`].
+#[track_caller]
+pub fn assert_interned_bounded<T: Staticize>(max: usize) {
+    let count = num_interned::<T>();
+    assert!(
+        count <= max,
+        "{count} values of type `{}` are interned, exceeding the bound of {max}",
+        std::any::type_name::<T::Static>(),
+    );
+}
+
+/// Reserves capacity for at least `additional` more values of type `T` to be interned by
+/// [`Interned`] on the current thread without the underlying storage having to grow. This is
+/// useful when a large number of values are about to be interned, such as at startup.
+pub fn reserve_interned<T: Staticize>(additional: usize) {
+    let type_id = T::static_type_id();
+    INTERNED.with(|interned| {
+        interned
+            .borrow_mut()
+            .entry(type_id)
+            .or_default()
+            .reserve(additional)
+    })
+}
+
+/// Reserves capacity for at least `additional` more values of type `T` to be memoized by
+/// [`Memoized`] on the current thread without the underlying storage having to grow.
+pub fn reserve_memoized<T: Staticize>(additional: usize) {
+    let type_id = T::static_type_id();
+    MEMOIZED.with(|memoized| {
+        memoized
+            .borrow_mut()
+            .entry(type_id)
+            .or_default()
+            .reserve(additional)
+    })
+}
+
+/// Removes every value memoized by [`Memoized::from_drainable`] for type `T` under the
+/// specified `scope` on the current thread, returning the values whose memo mappings were
+/// removed in the order they were memoized. Subsequent lookups of those inputs (see [`Memoized::get`]) miss until
+/// they are memoized again.
+///
+/// Only the memo mappings are removed: the values themselves stay interned, so any
+/// outstanding [`Memoized`] or [`Interned`] handles to them remain valid.
+///
+/// ```
+/// use interned::{drain_scope, Interned, Memoized};
+///
+/// let squared = Memoized::from_drainable("square", 4u32, |n| Interned::from(n * n));
+/// let drained = drain_scope::<u32>("square");
+/// assert_eq!(drained, [squared.interned()]);
+/// assert!(Memoized::<u32, u32>::get("square", 4).is_none());
+/// ```
+pub fn drain_scope<T: Hash + Staticize + DataType>(scope: impl Hash) -> Vec<Interned<T>> {
+    let type_id = T::static_type_id();
+    let keys = MEMOIZED_SCOPES.with(|scopes| {
+        scopes
+            .borrow_mut()
+            .remove(&memoized_scope_key(&scope, type_id))
+            .unwrap_or_default()
+    });
+    MEMOIZED.with(|memoized| {
+        let mut memoized = memoized.borrow_mut();
+        let Some(values) = memoized.get_mut(&type_id) else {
+            return Vec::new();
+        };
+        keys.iter()
+            .filter_map(|key| values.remove(key))
+            .map(|value| Interned {
+                _value: PhantomData,
+                value,
+            })
+            .collect()
+    })
+}
+
+/// Internal key identifying the memoized values of the [`TypeId`] `type_id` under `scope` in
+/// [`MEMOIZED_SCOPES`].
+fn memoized_scope_key(scope: &impl Hash, type_id: TypeId) -> u64 {
+    let mut hasher = DefaultHasher::default();
+    scope.hash(&mut hasher);
+    type_id.hash(&mut hasher);
+    hasher.finish()
+}
+
 /// Derives [`From<Interned<T>>`] for the specified value type.
 #[macro_export]
 macro_rules! derive_from_interned_impl_value {
     ($ty:ty) => {
         impl From<$crate::Interned<$ty>> for $ty {
-            fn from(value: Interned<$ty>) -> Self {
-                use $crate::_unsafe::Static::*;
-                match value.value {
-                    Value(val) => unsafe { *val.as_value() },
-                    _ => unreachable!(),
-                }
+            fn from(value: $crate::Interned<$ty>) -> Self {
+                *value.interned_value()
             }
         }
     };
@@ -399,12 +1034,8 @@ macro_rules! derive_from_interned_impl_value {
 macro_rules! derive_from_interned_impl_slice {
     ($ty:ty) => {
         impl From<$crate::Interned<$ty>> for $ty {
-            fn from(value: Interned<$ty>) -> Self {
-                use $crate::_unsafe::Static::*;
-                match value.value {
-                    Slice(slice) => unsafe { slice.as_slice() },
-                    _ => unreachable!(),
-                }
+            fn from(value: $crate::Interned<$ty>) -> Self {
+                value.interned_slice()
             }
         }
     };
@@ -428,6 +1059,39 @@ impl From<Interned<&Path>> for &Path {
     }
 }
 
+impl From<Interned<&str>> for String {
+    fn from(value: Interned<&str>) -> Self {
+        value.interned_str().to_string()
+    }
+}
+
+impl From<Interned<&OsStr>> for OsString {
+    fn from(value: Interned<&OsStr>) -> Self {
+        value.interned_os_str().to_os_string()
+    }
+}
+
+impl From<Interned<&Path>> for PathBuf {
+    fn from(value: Interned<&Path>) -> Self {
+        value.interned_path().to_path_buf()
+    }
+}
+
+impl<'a> From<Cow<'a, [u8]>> for Interned<&'static [u8]> {
+    // both variants are interned directly from the borrowed bytes, so nothing is allocated
+    // unless these bytes have never been interned before.
+    fn from(value: Cow<'a, [u8]>) -> Self {
+        Interned::from(value.as_ref())
+    }
+}
+
+impl From<Vec<u8>> for Interned<&'static [u8]> {
+    fn from(value: Vec<u8>) -> Self {
+        Interned::from(value.as_slice())
+    }
+}
+
+derive_from_interned_impl_value!(());
 derive_from_interned_impl_value!(char);
 derive_from_interned_impl_value!(bool);
 derive_from_interned_impl_value!(usize);
@@ -441,6 +1105,7 @@ derive_from_interned_impl_value!(i16);
 derive_from_interned_impl_value!(i32);
 derive_from_interned_impl_value!(i64);
 derive_from_interned_impl_value!(i128);
+derive_from_interned_impl_slice!(&[()]);
 derive_from_interned_impl_slice!(&[bool]);
 derive_from_interned_impl_slice!(&[usize]);
 derive_from_interned_impl_slice!(&[u8]);
@@ -453,3 +1118,297 @@ derive_from_interned_impl_slice!(&[i16]);
 derive_from_interned_impl_slice!(&[i32]);
 derive_from_interned_impl_slice!(&[i64]);
 derive_from_interned_impl_slice!(&[i128]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(interned_no_intern, ignore = "relies on de-duplication")]
+    fn test_no_hash_u64() {
+        let keys: Vec<u64> = (0..100_000u64)
+            .map(|i| {
+                let mut hasher = DefaultHasher::default();
+                i.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect();
+        for key in &keys[..16] {
+            assert_eq!(NoHashU64.hash_one(key), *key);
+        }
+
+        let mut no_hash: HashMap<u64, u64, NoHashU64> = HashMap::default();
+        for (i, key) in keys.iter().enumerate() {
+            no_hash.insert(*key, i as u64);
+        }
+        assert_eq!(no_hash.len(), keys.len());
+        for (i, key) in keys.iter().enumerate() {
+            assert_eq!(no_hash.get(key), Some(&(i as u64)));
+        }
+
+        let initial = num_interned::<u32>();
+        for i in 0..1000u32 {
+            assert_eq!(*Interned::from(i).interned_value(), i);
+        }
+        for i in 0..1000u32 {
+            assert_eq!(Interned::from(i).as_ptr(), Interned::from(i).as_ptr());
+        }
+        assert_eq!(num_interned::<u32>(), initial + 1000);
+    }
+
+    #[test]
+    fn test_type_id_hasher() {
+        let ids = [
+            TypeId::of::<u32>(),
+            TypeId::of::<i32>(),
+            TypeId::of::<&str>(),
+            TypeId::of::<&[u8]>(),
+            TypeId::of::<()>(),
+        ];
+        for (i, a) in ids.iter().enumerate() {
+            assert_eq!(
+                TypeIdHasherBuilder.hash_one(a),
+                TypeIdHasherBuilder.hash_one(a)
+            );
+            for b in &ids[i + 1..] {
+                assert_ne!(
+                    TypeIdHasherBuilder.hash_one(a),
+                    TypeIdHasherBuilder.hash_one(b)
+                );
+            }
+        }
+
+        // wider input is folded into the hash in 8-byte words
+        let mut hasher = TypeIdHasherBuilder.build_hasher();
+        hasher.write(&[0xab; 16]);
+        assert_eq!(hasher.finish(), 0);
+        let mut hasher = TypeIdHasherBuilder.build_hasher();
+        hasher.write(&[1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(
+            hasher.finish(),
+            u64::from_ne_bytes([3, 0, 0, 0, 0, 0, 0, 0])
+        );
+        let mut hasher = TypeIdHasherBuilder.build_hasher();
+        hasher.write_u128(u128::from(u64::MAX) << 64 | 1);
+        assert_eq!(hasher.finish(), u64::MAX - 1);
+    }
+
+    #[test]
+    #[cfg_attr(interned_no_intern, ignore = "relies on de-duplication")]
+    fn test_reserve_interned() {
+        fn interned_capacity<T: Staticize>() -> usize {
+            INTERNED.with(|interned| interned.borrow()[&T::static_type_id()].capacity())
+        }
+        fn memoized_capacity<T: Staticize>() -> usize {
+            MEMOIZED.with(|memoized| memoized.borrow()[&T::static_type_id()].capacity())
+        }
+
+        reserve_interned::<u128>(10_000);
+        let capacity = interned_capacity::<u128>();
+        assert!(capacity >= 10_000);
+        for i in 0..10_000u128 {
+            let _ = Interned::from(i);
+        }
+        assert_eq!(num_interned::<u128>(), 10_000);
+        assert_eq!(interned_capacity::<u128>(), capacity);
+
+        reserve_memoized::<i128>(1_000);
+        let capacity = memoized_capacity::<i128>();
+        assert!(capacity >= 1_000);
+        for i in 0..1_000i128 {
+            Memoized::from("reserve", i, |i| (i * 2).into());
+        }
+        assert_eq!(num_memoized::<i128>(), 1_000);
+        assert_eq!(memoized_capacity::<i128>(), capacity);
+    }
+
+    #[test]
+    #[cfg_attr(interned_no_intern, ignore = "relies on de-duplication")]
+    fn test_same_handle_comparisons_skip_contents() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static COMPARISONS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        #[derive(Copy, Clone, Eq, Debug)]
+        struct Counted(u64);
+
+        impl Hash for Counted {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.hash(state)
+            }
+        }
+
+        impl PartialEq for Counted {
+            fn eq(&self, other: &Self) -> bool {
+                COMPARISONS.with(|count| count.set(count.get() + 1));
+                self.0 == other.0
+            }
+        }
+
+        impl PartialOrd for Counted {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Counted {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                COMPARISONS.with(|count| count.set(count.get() + 1));
+                self.0.cmp(&other.0)
+            }
+        }
+
+        derive_staticize!(Counted);
+
+        let values: Vec<Counted> = (0..100_000).map(Counted).collect();
+        let a = intern(values.as_slice());
+        let b = intern(values.as_slice());
+        let before = COMPARISONS.with(Cell::get);
+        assert!(a == b);
+        assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
+        assert_eq!(a.partial_cmp(&b), Some(std::cmp::Ordering::Equal));
+        assert_eq!(a.value_ord(&b), std::cmp::Ordering::Equal);
+        assert_eq!(COMPARISONS.with(Cell::get), before);
+
+        // distinct handles still compare by value
+        let c = intern(&values[1..]);
+        assert_eq!(a.cmp(&c), std::cmp::Ordering::Less);
+        assert!(COMPARISONS.with(Cell::get) > before);
+    }
+
+    #[test]
+    #[cfg_attr(interned_no_intern, ignore = "relies on de-duplication")]
+    fn test_colliding_slices_intern_distinct_values() {
+        #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+        struct Colliding(u32);
+
+        impl Hash for Colliding {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                0u8.hash(state)
+            }
+        }
+
+        derive_staticize!(Colliding);
+
+        let a = intern([Colliding(1), Colliding(2)].as_slice());
+        let b = intern([Colliding(3), Colliding(4)].as_slice());
+        assert_eq!(a.value.hash_code(), b.value.hash_code());
+        assert_ne!(a, b);
+        assert_eq!(a.interned_slice(), &[Colliding(1), Colliding(2)]);
+        assert_eq!(b.interned_slice(), &[Colliding(3), Colliding(4)]);
+        assert_eq!(
+            intern([Colliding(3), Colliding(4)].as_slice()).as_ptr(),
+            b.as_ptr()
+        );
+        assert_eq!(num_interned::<&[Colliding]>(), 2);
+
+        let memoized: Memoized<usize, &[Colliding]> = Memoized::from("colliding", 3, |_| b);
+        assert_eq!(memoized.interned().as_ptr(), b.as_ptr());
+        assert_eq!(
+            Interned::<&[Colliding]>::from_static_checked(b.value),
+            Some(b)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(interned_no_intern, ignore = "relies on de-duplication")]
+    fn test_colliding_large_byte_slices_compare_by_content() {
+        let len = 1 << 20;
+        let mut bytes = vec![0xabu8; len];
+        let mut hasher = DefaultHasher::default();
+        bytes.as_slice().hash(&mut hasher);
+        let hash = hasher.finish();
+
+        // plant a decoy of the same length that only differs in its last byte at the exact key
+        // `bytes` hashes to, forcing a full content comparison when `bytes` is interned
+        bytes[len - 1] = 0;
+        let decoy = Static::from(bytes.as_slice(), Some(hash));
+        bytes[len - 1] = 0xab;
+        INTERNED.with(|interned| {
+            interned
+                .borrow_mut()
+                .entry(<&[u8]>::static_type_id())
+                .or_default()
+                .insert(hash, decoy)
+        });
+
+        let a = intern(bytes.as_slice());
+        assert_ne!(a.as_ptr(), decoy.as_ptr());
+        assert_eq!(a.interned_slice(), bytes.as_slice());
+        let b = intern(bytes.clone().as_slice());
+        assert_eq!(b.as_ptr(), a.as_ptr());
+        // re-interning the interned buffer itself is recognized without comparing any bytes
+        assert_eq!(intern(a.interned_slice()).as_ptr(), a.as_ptr());
+
+        // two distinct copies of equal large byte slices still compare equal by content
+        let copy = Static::from(bytes.as_slice(), Some(hash));
+        assert!(unsafe { copy._content_eq::<&[u8]>(&a.value) });
+        assert!(unsafe { !decoy._content_eq::<&[u8]>(&a.value) });
+        assert!(unsafe { a.value._content_eq::<&[u8]>(&a.value) });
+    }
+
+    #[test]
+    #[cfg_attr(interned_no_intern, ignore = "relies on de-duplication")]
+    fn test_checked_static_constructors() {
+        let a = Interned::<i32>::from(-9812);
+        let erased = a.as_static();
+        let b = Interned::<i32>::from_static_checked(erased).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.as_ptr(), b.as_ptr());
+        // a handle interned as one type is rejected as another
+        assert_eq!(Interned::<u32>::from_static_checked(erased), None);
+        // a handle that was never interned is rejected
+        let stray = Static::from_value(-9812i32, None);
+        assert_eq!(Interned::<i32>::from_static_checked(stray), None);
+
+        let s = Interned::<&str>::from("checked");
+        assert_eq!(
+            Interned::<&str>::from_static_checked(s.as_static()),
+            Some(s)
+        );
+        assert_eq!(Interned::<&[u8]>::from_static_checked(s.as_static()), None);
+
+        let m = Memoized::<usize, i32>::from_interned(a);
+        assert_eq!(m.interned(), a);
+        assert_eq!(*m.as_value(), -9812);
+
+        // handles of other types never pass as `T`, even when the value would fit
+        let byte = Interned::<u8>::from(1u8);
+        assert_eq!(
+            Interned::<u8>::from_static_checked(byte.as_static()),
+            Some(byte)
+        );
+        assert_eq!(Interned::<u64>::from_static_checked(byte.as_static()), None);
+
+        let first_true = Interned::from(true);
+        assert_eq!(
+            Interned::<bool>::from_static_checked(first_true.as_static()),
+            Some(first_true)
+        );
+
+        // colliding values are found at their probed keys
+        #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+        struct Colliding(u32);
+
+        impl Hash for Colliding {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                0u8.hash(state)
+            }
+        }
+
+        derive_staticize!(Colliding);
+        unsafe_impl_data_type!(Colliding, Value);
+        derive_from_interned_impl_value!(Colliding);
+
+        let colliding: Vec<Interned<Colliding>> =
+            (0..3).map(|i| Interned::from(Colliding(i))).collect();
+        for handle in &colliding {
+            assert_eq!(
+                Interned::<Colliding>::from_static_checked(handle.as_static()),
+                Some(*handle)
+            );
+        }
+    }
+}
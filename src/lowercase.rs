@@ -0,0 +1,112 @@
+//! Home of [`LowercaseInterned`], a lowercase-insensitive interned string handle that
+//! remembers the original spelling it was created from.
+
+use crate::*;
+
+/// A lowercase-insensitive interned string, useful for symbol tables that must compare names
+/// without regard to case while still preserving the original spelling for display.
+///
+/// A [`LowercaseInterned`] is a small wrapper around two [`Interned<&str>`] handles: the
+/// lowercased _key_ (used for equality, ordering, and hashing) and the _original_ string it
+/// was created from (reported via [`LowercaseInterned::original`]).
+///
+/// Note that the key is produced with [`str::to_lowercase`], which is _not_ full Unicode case
+/// folding: strings that only match under full folding, such as `"ß"` and `"SS"`, compare
+/// unequal. This is exact for ASCII and matches most other scripts, but should not be relied
+/// upon as a Unicode caseless match.
+///
+/// ```
+/// use interned::LowercaseInterned;
+///
+/// let a = LowercaseInterned::from("Foo");
+/// let b = LowercaseInterned::from("foo");
+/// assert_eq!(a, b);
+/// assert_eq!(a.lowercased(), "foo");
+/// assert_ne!(LowercaseInterned::from("ß"), LowercaseInterned::from("SS"));
+/// assert_eq!(a.original(), "Foo");
+/// assert_eq!(b.original(), "foo");
+/// ```
+#[derive(Copy, Clone)]
+pub struct LowercaseInterned {
+    lowercased: Interned<&'static str>,
+    original: Interned<&'static str>,
+}
+
+impl LowercaseInterned {
+    /// Returns the lowercased form of this string, which is what equality, ordering, and
+    /// hashing are based on.
+    pub fn lowercased(&self) -> &'static str {
+        self.lowercased.interned_str()
+    }
+
+    /// Returns the original spelling this [`LowercaseInterned`] was created from.
+    pub fn original(&self) -> &'static str {
+        self.original.interned_str()
+    }
+
+    /// Returns the underlying [`Interned`] for the lowercased form of this string.
+    pub fn interned_lowercased(&self) -> Interned<&'static str> {
+        self.lowercased
+    }
+
+    /// Returns the underlying [`Interned`] for the original spelling of this string.
+    pub fn interned_original(&self) -> Interned<&'static str> {
+        self.original
+    }
+}
+
+impl<'a> From<&'a str> for LowercaseInterned {
+    fn from(value: &'a str) -> Self {
+        LowercaseInterned {
+            lowercased: Interned::from(value.to_lowercase().as_str()),
+            original: Interned::from(value),
+        }
+    }
+}
+
+impl From<String> for LowercaseInterned {
+    fn from(value: String) -> Self {
+        LowercaseInterned::from(value.as_str())
+    }
+}
+
+impl PartialEq for LowercaseInterned {
+    fn eq(&self, other: &Self) -> bool {
+        self.lowercased == other.lowercased
+    }
+}
+
+impl Eq for LowercaseInterned {}
+
+impl PartialOrd for LowercaseInterned {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LowercaseInterned {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.lowercased.cmp(&other.lowercased)
+    }
+}
+
+impl Hash for LowercaseInterned {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.lowercased.hash(state)
+    }
+}
+
+impl std::fmt::Debug for LowercaseInterned {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LowercaseInterned")
+            .field("lowercased", &self.lowercased())
+            .field("original", &self.original())
+            .finish()
+    }
+}
+
+impl Display for LowercaseInterned {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.original())
+    }
+}
@@ -15,8 +15,7 @@ use crate::*;
 #[derive(Copy, Clone)]
 pub struct Memoized<I: Hash, T: Hash + Staticize + DataType> {
     _input: PhantomData<I>,
-    #[doc(hidden)]
-    pub interned: Interned<T>,
+    interned: Interned<T>,
 }
 
 impl<I: Hash, T: Hash + Staticize + DataType> Memoized<I, T> {
@@ -28,6 +27,70 @@ impl<I: Hash, T: Hash + Staticize + DataType> Memoized<I, T> {
             value: self.interned.value,
         }
     }
+
+    /// Returns the underlying heap pointer of the memoized value (see [`Interned::as_ptr`]).
+    #[inline]
+    pub fn as_ptr(&self) -> *const () {
+        self.interned.as_ptr()
+    }
+
+    /// Returns a compact `u64` token identifying the memoized value (see
+    /// [`Interned::token`]), useful for keying memoized results in side tables.
+    #[inline]
+    pub fn token(&self) -> u64 {
+        self.interned.token()
+    }
+
+    /// Wraps an existing [`Interned`] value in a [`Memoized`] with input type `I`, without
+    /// running any generator or recording anything in memoized storage.
+    ///
+    /// This is the supported way to construct a [`Memoized`] from a value that was interned
+    /// elsewhere, for example when seeding a cache.
+    #[inline]
+    pub fn from_interned(interned: Interned<T>) -> Self {
+        Memoized {
+            _input: PhantomData,
+            interned,
+        }
+    }
+}
+
+impl<I: Hash, T: Hash + Staticize + DataType> Memoized<I, T> {
+    /// Derives a second memoized value from this one by applying `f` to the memoized value.
+    ///
+    /// The derived value is memoized under a key made from the heap address and hash of this
+    /// memoized value and the type of `f` (rather than from the original input, which is not
+    /// retained), so `f` only runs the first time a given call site maps a given value on
+    /// the current thread. The hash is included because zero-sized slices of different
+    /// lengths share an address (see [`Interned::token`]). Because equal values share both,
+    /// mapping two [`Memoized`]s that hold the same value also shares the result. `f` should therefore be
+    /// a pure function of the value it is passed; the `'static` bound rules out borrowed
+    /// captures, but any state moved into `f` is ignored on a cache hit.
+    pub fn map<U, F>(&self, f: F) -> Memoized<I, U>
+    where
+        U: Hash + Copy + Staticize + DataType,
+        U::Static: Hash + Copy + Clone + DataType,
+        F: FnOnce(&T::DerefTargetType) -> Interned<U> + 'static,
+    {
+        let mapped = Memoized::<(usize, u64), U>::from_once(
+            ("Memoized::map", TypeId::of::<F>()),
+            (self.as_ptr() as usize, self.interned.value.hash_code()),
+            |_| f(&**self),
+        );
+        Memoized::from_interned(mapped.interned())
+    }
+}
+
+impl<I: Hash, T: Hash + Ord + Staticize + DataType> Memoized<I, T>
+where
+    <T as DataType>::SliceValueType: Ord,
+{
+    /// Compares the underlying memoized value of this [`Memoized`] with that of `other` by
+    /// value. Unlike [`Ord`], `other` may have a different input type `J`, so memoizations
+    /// with different key types but the same output type `T` can be ordered together.
+    pub fn value_cmp<J: Hash>(&self, other: &Memoized<J, T>) -> std::cmp::Ordering {
+        self.interned().cmp(&other.interned())
+    }
 }
 
 impl<I: Hash, T: Hash + Staticize + DataType<Type = Slice>> Memoized<I, T> {
@@ -72,32 +135,113 @@ where
     ///
     /// Thus [`Memoized`] provides perfect memory de-duplication for all memoized values.
     ///
+    /// `generator` is free to call [`Memoized::from`] itself, which makes it easy to memoize
+    /// recursive functions by memoizing each subproblem.
+    ///
     /// See the docs at the root of the crate for some examples.
     pub fn from<S, G>(scope: S, input: I, generator: G) -> Memoized<I, T>
     where
         S: Hash,
         G: Fn(I) -> Interned<T>,
     {
-        let mut hasher = DefaultHasher::default();
+        Memoized::from_once(scope, input, generator)
+    }
+
+    /// Identical to [`Memoized::from`], except `generator` only needs to implement [`FnOnce`],
+    /// allowing it to capture (and consume) non-[`Copy`] state by move.
+    ///
+    /// `generator` is only called on a cache miss. On a hit, it is dropped without being
+    /// called.
+    pub fn from_once<S, G>(scope: S, input: I, generator: G) -> Memoized<I, T>
+    where
+        S: Hash,
+        G: FnOnce(I) -> Interned<T>,
+    {
+        Memoized::memoize(scope, input, generator, false)
+    }
+
+    /// Identical to [`Memoized::from_once`], except a newly memoized value is also recorded
+    /// under `scope` so that it can later be removed with [`drain_scope`]. Values memoized via
+    /// [`Memoized::from`] or [`Memoized::from_once`] are not recorded, so they skip this
+    /// bookkeeping and are left alone by [`drain_scope`].
+    pub fn from_drainable<S, G>(scope: S, input: I, generator: G) -> Memoized<I, T>
+    where
+        S: Hash,
+        G: FnOnce(I) -> Interned<T>,
+    {
+        Memoized::memoize(scope, input, generator, true)
+    }
+
+    fn memoize<S, G>(scope: S, input: I, generator: G, drainable: bool) -> Memoized<I, T>
+    where
+        S: Hash,
+        G: FnOnce(I) -> Interned<T>,
+    {
+        let type_id = T::static_type_id();
+        let input_hash = Self::input_hash(&scope, &input, type_id);
+        let existing = Self::lookup(type_id, input_hash);
+        // the `MEMOIZED` borrow is released before `generator` runs, so that generators can
+        // themselves (recursively) call into `Memoized` for subproblems
+        let value_static = existing.unwrap_or_else(|| {
+            let generated = generator(input).as_static();
+            let (value, inserted) = MEMOIZED.with(|memoized| {
+                match memoized
+                    .borrow_mut()
+                    .entry(type_id)
+                    .or_insert_with(HashMap::default)
+                    .entry(input_hash)
+                {
+                    Entry::Occupied(entry) => (*entry.get(), false),
+                    Entry::Vacant(entry) => (*entry.insert(generated), true),
+                }
+            });
+            if inserted && drainable {
+                MEMOIZED_SCOPES.with(|scopes| {
+                    scopes
+                        .borrow_mut()
+                        .entry(memoized_scope_key(&scope, type_id))
+                        .or_default()
+                        .push(input_hash)
+                });
+            }
+            value
+        });
+        // `value_static` always comes from an `Interned` produced by `generator`, so it is
+        // already canonical and can be wrapped directly
+        Self::from_static(value_static)
+    }
+
+    /// Returns the value memoized for `input` under `scope`, if any, without generating it
+    /// otherwise. See [`Memoized::from`].
+    pub fn get<S: Hash>(scope: S, input: I) -> Option<Memoized<I, T>> {
         let type_id = T::static_type_id();
+        Self::lookup(type_id, Self::input_hash(&scope, &input, type_id)).map(Self::from_static)
+    }
+
+    fn input_hash<S: Hash>(scope: &S, input: &I, type_id: TypeId) -> u64 {
+        let mut hasher = DefaultHasher::default();
         input.hash(&mut hasher);
         scope.hash(&mut hasher);
         type_id.hash(&mut hasher);
-        let input_hash = hasher.finish();
-        let value_static = MEMOIZED.with(|memoized| {
-            match (*memoized)
-                .borrow_mut()
-                .entry(type_id)
-                .or_insert_with(|| HashMap::new())
-                .entry(input_hash)
-            {
-                Entry::Occupied(entry) => *entry.get(),
-                Entry::Vacant(entry) => *entry.insert(generator(input).value),
-            }
-        });
+        hasher.finish()
+    }
+
+    fn lookup(type_id: TypeId, input_hash: u64) -> Option<Static> {
+        MEMOIZED.with(|memoized| {
+            memoized
+                .borrow()
+                .get(&type_id)
+                .and_then(|values| values.get(&input_hash).copied())
+        })
+    }
+
+    fn from_static(value: Static) -> Memoized<I, T> {
         Memoized {
             _input: PhantomData,
-            interned: value_static.into(),
+            interned: Interned {
+                _value: PhantomData,
+                value,
+            },
         }
     }
 }
@@ -142,7 +286,7 @@ impl<I: Hash, T: Hash + Eq + Staticize + DataType> Eq for Memoized<I, T> where
 
 impl<I: Hash, T: Hash + PartialOrd + Staticize + DataType> PartialOrd for Memoized<I, T>
 where
-    <T as DataType>::SliceValueType: PartialEq,
+    <T as DataType>::SliceValueType: PartialOrd,
 {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         self.interned().partial_cmp(&other.interned())
@@ -151,7 +295,7 @@ where
 
 impl<I: Hash, T: Hash + Ord + Staticize + DataType> Ord for Memoized<I, T>
 where
-    <T as DataType>::SliceValueType: PartialEq,
+    <T as DataType>::SliceValueType: Ord,
 {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.interned().cmp(&other.interned())
@@ -0,0 +1,136 @@
+//! Merkle-style digests of interned slices, for using them as the leaves of content-addressed
+//! trees. Enabled by the `sha2` feature.
+//!
+//! Each element of a slice is encoded into bytes (see [`MerkleLeaf`]) and hashed into a
+//! SHA-256 leaf digest, and the leaf digests are then [`combine`]d pairwise, level by level,
+//! into a single root digest (an odd digest out at the end of a level is carried up
+//! unchanged). Leaf and parent digests are domain-separated, so a leaf can never be mistaken
+//! for a parent.
+//!
+//! Digests only depend on the contents of a slice, not on where it is interned, and element
+//! encodings are fixed (little-endian, fixed-width integers), so digests are stable across
+//! threads, runs, platforms and Rust versions.
+//!
+//! ```
+//! use interned::{merkle, Interned};
+//!
+//! let left: Interned<&[u32]> = Interned::from([1u32, 2].as_slice());
+//! let right: Interned<&[u32]> = Interned::from([3u32].as_slice());
+//! let parent = merkle::combine(&left.merkle_hash(), &right.merkle_hash());
+//! assert_ne!(parent, left.merkle_hash());
+//! ```
+
+use crate::*;
+use sha2::{Digest, Sha256};
+
+/// Prefix of the data hashed into a leaf digest.
+const LEAF_PREFIX: u8 = 0;
+
+/// Prefix of the data hashed into a parent digest.
+const PARENT_PREFIX: u8 = 1;
+
+/// Hashes the digests of two child nodes into the digest of their parent.
+pub fn combine(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([PARENT_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// An element type that can be hashed into a Merkle leaf by [`Interned::merkle_hash`].
+///
+/// Implementations must write a fixed, platform-independent encoding of the element, so that
+/// digests stay stable. Each element is hashed into a leaf of its own, so variable-length
+/// encodings don't need to be length-prefixed.
+pub trait MerkleLeaf {
+    /// Appends the canonical byte encoding of this element to `buf`.
+    fn encode_leaf(&self, buf: &mut Vec<u8>);
+}
+
+macro_rules! impl_merkle_leaf_le_bytes {
+    ($($ty:ty),*) => {
+        $(
+            impl MerkleLeaf for $ty {
+                fn encode_leaf(&self, buf: &mut Vec<u8>) {
+                    buf.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_merkle_leaf_le_bytes!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl MerkleLeaf for usize {
+    fn encode_leaf(&self, buf: &mut Vec<u8>) {
+        // always encoded as 64 bits, regardless of the platform's pointer width
+        (*self as u64).encode_leaf(buf)
+    }
+}
+
+impl MerkleLeaf for isize {
+    fn encode_leaf(&self, buf: &mut Vec<u8>) {
+        (*self as i64).encode_leaf(buf)
+    }
+}
+
+impl MerkleLeaf for bool {
+    fn encode_leaf(&self, buf: &mut Vec<u8>) {
+        buf.push(*self as u8);
+    }
+}
+
+impl MerkleLeaf for char {
+    fn encode_leaf(&self, buf: &mut Vec<u8>) {
+        (*self as u32).encode_leaf(buf)
+    }
+}
+
+impl MerkleLeaf for &str {
+    fn encode_leaf(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl MerkleLeaf for &[u8] {
+    fn encode_leaf(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self);
+    }
+}
+
+impl<T: Hash + PartialEq + Copy + Staticize<Static = T> + MerkleLeaf> Interned<&'static [T]> {
+    /// Computes the Merkle root digest of the underlying slice. Equal slices always have equal
+    /// digests. See the [module docs](crate::merkle) for how the digest is computed.
+    ///
+    /// The digest of an empty slice is the SHA-256 digest of no data.
+    ///
+    /// Requires the `sha2` feature.
+    pub fn merkle_hash(&self) -> [u8; 32] {
+        let mut buf = Vec::new();
+        let mut level: Vec<[u8; 32]> = self
+            .interned_slice()
+            .iter()
+            .map(|item| {
+                buf.clear();
+                buf.push(LEAF_PREFIX);
+                item.encode_leaf(&mut buf);
+                Sha256::digest(&buf).into()
+            })
+            .collect();
+        if level.is_empty() {
+            return Sha256::digest([]).into();
+        }
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => combine(left, right),
+                    [odd] => *odd,
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        level[0]
+    }
+}
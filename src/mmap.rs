@@ -0,0 +1,294 @@
+//! Contains [`MmapInterner`], an interner backed by a shared memory-mapped file so that
+//! multiple processes (such as the workers of a build system) can de-duplicate strings
+//! against each other. Enabled by the `mmap` feature.
+//!
+//! Unlike [`Interned`], which keeps each thread's values in process-local heap memory, an
+//! [`MmapInterner`] appends every new string to a file that is mapped into memory. Every
+//! process that opens the same file sees the strings appended by every other process, and
+//! the file persists between runs, so strings interned in a previous run are found again
+//! without being re-appended.
+//!
+//! Currently only strings are supported, and the file is append-only: strings are never
+//! removed, and the file has a fixed capacity chosen when it is opened.
+//!
+//! # File Format
+//!
+//! The file starts with a 16-byte header holding a magic number and the number of bytes
+//! used so far (including the header). Each string follows as a 4-byte length word followed
+//! by its UTF-8 bytes, padded to a multiple of 4 bytes. Processes reserve space for a string
+//! by atomically bumping the used length in the header, immediately mark the reservation as
+//! pending in its length word (recording the reserved size), and publish it by writing its
+//! plain length word last, so readers never observe a partially written string.
+//!
+//! # Crashed Writers
+//!
+//! Readers step over pending reservations using the size recorded in their length word and
+//! check back on them in later scans, so a process that crashes (or stalls) while writing a
+//! string only loses that one string: everything appended after it stays visible. The one
+//! gap is a crash in the instant between bumping the used length and marking the
+//! reservation as pending, which leaves a length word of `0` whose size is unknown. Scans
+//! stop at such a word, so strings appended after it are only found by the processes that
+//! appended them (and can end up being appended again by others); if that happens, the file
+//! has to be deleted and rebuilt.
+
+use crate::*;
+use memmap2::MmapRaw;
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs::OpenOptions,
+    io,
+    sync::{
+        atomic::{AtomicU32, AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+/// Magic number identifying a file created by [`MmapInterner`].
+const MAGIC: [u8; 8] = *b"INTERNED";
+
+/// Length of the file header: the magic number followed by the used length.
+const HEADER_LEN: usize = 16;
+
+/// Length of the word preceding each string, holding its length plus one (`0` means the
+/// space has been reserved but not yet marked [`PENDING`]).
+const WORD_LEN: usize = 4;
+
+/// Flag set in a length word while its string is still being written. The remaining bits
+/// hold the length plus one, so readers can step over the reservation in the meantime.
+const PENDING: u32 = 1 << 31;
+
+/// An interner that stores strings in a shared memory-mapped file, de-duplicating them
+/// across every process that opens the same file.
+///
+/// See the [module docs](self) for more information.
+///
+/// ```
+/// use interned::mmap::MmapInterner;
+///
+/// let path = std::env::temp_dir().join(format!("interned-doc-{}.bin", std::process::id()));
+/// # let _ = std::fs::remove_file(&path);
+/// let interner = unsafe { MmapInterner::open(&path, 4096) }.unwrap();
+/// let a = interner.intern("hello").unwrap();
+/// let b = interner.intern("hello").unwrap();
+/// assert_eq!(a.as_ptr(), b.as_ptr());
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub struct MmapInterner {
+    map: &'static MmapRaw,
+    index: Mutex<MmapIndex>,
+}
+
+/// Internal (per-process) index of the strings stored in an [`MmapInterner`]'s file.
+struct MmapIndex {
+    /// Offset up to which the file has been scanned into `strs`.
+    scanned: usize,
+    /// Offsets of strings that were still being written when the scan stepped over them,
+    /// which are checked again on every later scan.
+    pending: Vec<usize>,
+    /// Every string found in the file, keyed by the hash of its contents. Strings appended
+    /// concurrently by different processes may occur in the file more than once, in which
+    /// case lookups consistently return whichever copy was indexed first.
+    strs: HashMap<u64, Vec<&'static str>>,
+}
+
+impl MmapInterner {
+    /// Opens (creating it if needed) the interner file at `path`, growing it to at least
+    /// `capacity` bytes, and maps it into memory.
+    ///
+    /// The mapping is never unmapped (even once the [`MmapInterner`] is dropped), which is
+    /// what allows interned strings to be handed out as `&'static str`.
+    ///
+    /// # Safety
+    ///
+    /// The returned strings point directly into the shared mapping, so the file must not be
+    /// modified, truncated, or deleted other than through an [`MmapInterner`] for as long as
+    /// the current process is running.
+    pub unsafe fn open(path: impl AsRef<Path>, capacity: usize) -> io::Result<MmapInterner> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        let capacity = capacity.max(HEADER_LEN) as u64;
+        if file.metadata()?.len() < capacity {
+            file.set_len(capacity)?;
+        }
+        let map: &'static MmapRaw = Box::leak(Box::new(MmapRaw::map_raw(&file)?));
+        let interner = MmapInterner {
+            map,
+            index: Mutex::new(MmapIndex {
+                scanned: HEADER_LEN,
+                pending: Vec::new(),
+                strs: HashMap::new(),
+            }),
+        };
+        let magic = interner.map.as_mut_ptr() as *mut [u8; 8];
+        if interner
+            .used()
+            .compare_exchange(0, HEADER_LEN as u64, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            magic.write(MAGIC);
+        } else if *magic != MAGIC && *magic != [0; 8] {
+            // an all-zero magic number means another process is initializing the file
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "not an interned mmap file",
+            ));
+        }
+        Ok(interner)
+    }
+
+    /// Interns `value` in the shared file, returning the existing copy if `value` was already
+    /// interned (by any process), and appending it to the file otherwise.
+    ///
+    /// Returns an error of kind [`io::ErrorKind::StorageFull`] if the file has no room left
+    /// for `value`.
+    pub fn intern(&self, value: &str) -> io::Result<&'static str> {
+        let mut index = self.lock();
+        self.scan(&mut index);
+        let hash = Self::hash(value);
+        if let Some(existing) = index.find(hash, value) {
+            return Ok(existing);
+        }
+        let stored = self.append(value)?;
+        // index `stored` right away, since a string still being written by another process
+        // ahead of it in the file can hold up the scan from reaching it
+        index.strs.entry(hash).or_default().push(stored);
+        Ok(stored)
+    }
+
+    /// Returns the copy of `value` stored in the shared file, if any process has interned it,
+    /// without appending it otherwise.
+    pub fn lookup(&self, value: &str) -> Option<&'static str> {
+        let mut index = self.lock();
+        self.scan(&mut index);
+        index.find(Self::hash(value), value)
+    }
+
+    /// Returns the number of bytes of the file currently in use, including the header.
+    pub fn bytes_used(&self) -> usize {
+        self.used().load(Ordering::Acquire) as usize
+    }
+
+    /// Returns the capacity of the file in bytes, as fixed when it was opened.
+    pub fn capacity(&self) -> usize {
+        self.map.len()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MmapIndex> {
+        self.index
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn hash(value: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn used(&self) -> &AtomicU64 {
+        // SAFETY: the header is 8-byte aligned (the mapping is page-aligned) and lies within
+        // the mapping, which lives forever
+        unsafe { &*(self.map.as_mut_ptr().add(8) as *const AtomicU64) }
+    }
+
+    fn word(&self, offset: usize) -> &AtomicU32 {
+        // SAFETY: entries start at 4-byte aligned offsets within the mapping
+        unsafe { &*(self.map.as_mut_ptr().add(offset) as *const AtomicU32) }
+    }
+
+    /// Indexes any strings appended to the file (by any process) since the last scan, along
+    /// with any previously pending strings that have since been published.
+    fn scan(&self, index: &mut MmapIndex) {
+        let mut pending = std::mem::take(&mut index.pending);
+        pending.retain(|&offset| {
+            let word = self.word(offset).load(Ordering::Acquire);
+            if word & PENDING != 0 {
+                return true;
+            }
+            self.index_str(index, offset, word as usize - 1);
+            false
+        });
+        index.pending = pending;
+        let end = (self.used().load(Ordering::Acquire) as usize).min(self.capacity());
+        while index.scanned + WORD_LEN <= end {
+            let offset = index.scanned;
+            let word = self.word(offset).load(Ordering::Acquire);
+            if word == 0 {
+                // reserved but not yet marked pending, so its size is unknown and the scan
+                // has to pick up from here later (see the module docs)
+                break;
+            }
+            let len = (word & !PENDING) as usize - 1;
+            if offset + WORD_LEN + len > end {
+                break;
+            }
+            if word & PENDING != 0 {
+                // still being written by another process, so step over it for now
+                index.pending.push(offset);
+            } else {
+                self.index_str(index, offset, len);
+            }
+            index.scanned = offset + WORD_LEN + len.next_multiple_of(WORD_LEN);
+        }
+    }
+
+    /// Indexes the published string of length `len` whose length word is at `offset`.
+    fn index_str(&self, index: &mut MmapIndex, offset: usize, len: usize) {
+        // SAFETY: published strings are never modified again and the mapping lives forever
+        let bytes: &'static [u8] =
+            unsafe { std::slice::from_raw_parts(self.map.as_ptr().add(offset + WORD_LEN), len) };
+        if let Ok(value) = std::str::from_utf8(bytes) {
+            let strs = index.strs.entry(Self::hash(value)).or_default();
+            // strings appended by this process are indexed as soon as they are appended
+            if !strs.iter().any(|stored| stored.as_ptr() == value.as_ptr()) {
+                strs.push(value);
+            }
+        }
+    }
+
+    /// Reserves space for `value` at the end of the file, writes it, and publishes it.
+    fn append(&self, value: &str) -> io::Result<&'static str> {
+        if value.len() >= (PENDING - 1) as usize {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "string too long for an interned mmap file",
+            ));
+        }
+        let size = (WORD_LEN + value.len().next_multiple_of(WORD_LEN)) as u64;
+        let capacity = self.capacity() as u64;
+        let offset =
+            self.used()
+                .fetch_update(Ordering::AcqRel, Ordering::Acquire, |used| {
+                    (used + size <= capacity).then_some(used + size)
+                })
+                .map_err(|_| io::Error::from(io::ErrorKind::StorageFull))? as usize;
+        // mark the reservation as pending straight away, so that readers can step over it
+        // even if this process dies before publishing it
+        self.word(offset)
+            .store(PENDING | (value.len() as u32 + 1), Ordering::Release);
+        // SAFETY: the reserved range lies within the mapping and is exclusively ours until
+        // it is published by writing its length word
+        let stored = unsafe {
+            let dst = self.map.as_mut_ptr().add(offset + WORD_LEN);
+            std::ptr::copy_nonoverlapping(value.as_ptr(), dst, value.len());
+            std::str::from_utf8_unchecked(std::slice::from_raw_parts(dst, value.len()))
+        };
+        self.word(offset)
+            .store(value.len() as u32 + 1, Ordering::Release);
+        Ok(stored)
+    }
+}
+
+impl MmapIndex {
+    fn find(&self, hash: u64, value: &str) -> Option<&'static str> {
+        self.strs
+            .get(&hash)?
+            .iter()
+            .find(|stored| **stored == value)
+            .copied()
+    }
+}
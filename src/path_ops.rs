@@ -0,0 +1,19 @@
+//! Convenience operations on interned paths (`Interned<&Path>`) that produce new interned
+//! values. Every value produced by these methods is interned in its own right, meaning it is
+//! de-duplicated against (and shares heap storage with) any equal value already interned.
+
+use crate::*;
+
+impl Interned<&'static Path> {
+    /// Splits the underlying path into its [`Component`](std::path::Component)s (as per
+    /// [`Path::components`]), interning the [`OsStr`] of each one.
+    ///
+    /// Components shared between paths (such as a common prefix) resolve to the same
+    /// interned handle, which makes this a good fit for building path-based tries.
+    pub fn interned_components(&self) -> Vec<Interned<&'static OsStr>> {
+        self.interned_path()
+            .components()
+            .map(|component| Interned::from(component.as_os_str()))
+            .collect()
+    }
+}
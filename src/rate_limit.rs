@@ -0,0 +1,103 @@
+//! Home of [`RateLimitedInterner`], which caps how quickly new values can be interned.
+//!
+//! Interned values are never freed, so interning untrusted input (for example on a server)
+//! lets an attacker grow memory without bound simply by sending many distinct values.
+//! [`RateLimitedInterner`] guards the thread-local interner with a token bucket: every
+//! _new_ value costs a token, tokens refill at a fixed rate, and once the bucket is empty new
+//! values are rejected with [`RateLimited`] until it refills. Values that are already interned
+//! are always returned, since they cost no additional memory.
+
+use crate::*;
+use std::time::{Duration, Instant};
+
+/// The error returned by [`RateLimitedInterner::intern`] when interning a new value would
+/// exceed the configured rate.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct RateLimited;
+
+impl Display for RateLimited {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("interning rate limit exceeded")
+    }
+}
+
+impl std::error::Error for RateLimited {}
+
+/// Interns values into the thread-local interner (exactly like [`Interned::from`]) while
+/// allowing at most `max_new` _new_ values per `window` on average.
+///
+/// The limit is enforced as a token bucket holding up to `max_new` tokens that refills
+/// continuously at a rate of `max_new` tokens per `window`, so short bursts up to `max_new`
+/// are allowed. Hits on already-interned values never consume a token and are never rejected.
+///
+/// ```
+/// use interned::{RateLimited, RateLimitedInterner};
+/// use std::time::Duration;
+///
+/// let mut interner = RateLimitedInterner::new(2, Duration::from_secs(60));
+/// assert!(interner.intern("rate limited a").is_ok());
+/// assert!(interner.intern("rate limited b").is_ok());
+/// assert_eq!(interner.intern("rate limited c"), Err(RateLimited));
+/// // already-interned values are always allowed
+/// assert!(interner.intern("rate limited a").is_ok());
+/// ```
+#[derive(Clone, Debug)]
+pub struct RateLimitedInterner {
+    max_new: usize,
+    window: Duration,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimitedInterner {
+    /// Creates a new [`RateLimitedInterner`] allowing `max_new` new values per `window`,
+    /// starting with a full bucket.
+    pub fn new(max_new: usize, window: Duration) -> Self {
+        RateLimitedInterner {
+            max_new,
+            window,
+            tokens: max_new as f64,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Returns the number of new values that could be interned right now without being
+    /// rate limited.
+    pub fn remaining(&mut self) -> usize {
+        self.refill();
+        self.tokens as usize
+    }
+
+    /// Interns `value`, returning [`RateLimited`] instead if `value` has not been interned
+    /// before and the rate limit has been exhausted.
+    pub fn intern<T: Hash + PartialEq + Copy + Staticize + DataType>(
+        &mut self,
+        value: T,
+    ) -> Result<Interned<T::Static>, RateLimited>
+    where
+        <T as Staticize>::Static: Hash + Sized,
+    {
+        if let Some(existing) = lookup(&value) {
+            return Ok(existing);
+        }
+        self.refill();
+        if self.tokens < 1.0 {
+            return Err(RateLimited);
+        }
+        self.tokens -= 1.0;
+        Ok(intern(value))
+    }
+
+    /// Adds the tokens accrued since the last refill, capped at `max_new`.
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill);
+        self.last_refill = now;
+        let accrued = if self.window.is_zero() {
+            f64::INFINITY
+        } else {
+            elapsed.as_secs_f64() / self.window.as_secs_f64() * self.max_new as f64
+        };
+        self.tokens = (self.tokens + accrued).min(self.max_new as f64);
+    }
+}
@@ -0,0 +1,62 @@
+//! [`rkyv`] support for interned types, enabled by the `rkyv` feature.
+//!
+//! [`InStr`] archives as an [`ArchivedString`] and `Interned<&[u8]>` archives as an
+//! [`ArchivedVec<u8>`], so archives contain the underlying bytes and can be accessed
+//! zero-copy (e.g. from a memory-mapped file) without touching the interner at all.
+//! Deserializing re-interns the archived contents, de-duplicating them against anything
+//! already interned on the current thread.
+
+use crate::*;
+use rkyv::{
+    rancor::{Fallible, Source},
+    ser::{Allocator, Writer},
+    string::{ArchivedString, StringResolver},
+    vec::{ArchivedVec, VecResolver},
+    Archive, Deserialize, Place, Serialize, SerializeUnsized,
+};
+
+impl Archive for InStr {
+    type Archived = ArchivedString;
+    type Resolver = StringResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedString::resolve_from_str(self.as_str(), resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for InStr
+where
+    S::Error: Source,
+    str: SerializeUnsized<S>,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedString::serialize_from_str(self.as_str(), serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<InStr, D> for ArchivedString {
+    fn deserialize(&self, _: &mut D) -> Result<InStr, D::Error> {
+        Ok(InStr::from(self.as_str()))
+    }
+}
+
+impl Archive for Interned<&'static [u8]> {
+    type Archived = ArchivedVec<u8>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedVec::resolve_from_slice(self.interned_slice(), resolver, out);
+    }
+}
+
+impl<S: Fallible + Allocator + Writer + ?Sized> Serialize<S> for Interned<&'static [u8]> {
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::serialize_from_slice(self.interned_slice(), serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Interned<&'static [u8]>, D> for ArchivedVec<u8> {
+    fn deserialize(&self, _: &mut D) -> Result<Interned<&'static [u8]>, D::Error> {
+        Ok(Interned::from(self.as_slice()))
+    }
+}
@@ -0,0 +1,256 @@
+//! Home of [`SharedInterned`], a process-wide (rather than thread-local) interned handle.
+//!
+//! [`Interned`] values live in thread-local storage, which keeps interning cheap and
+//! lock-free but means a value interned on one thread has a different heap address than the
+//! same value interned on another. [`SharedInterned`] instead interns into a single global
+//! pool guarded by a [`Mutex`], so every thread resolves equal values to the same pointer and
+//! handles can be sent freely between threads. Strings and slices are supported. With the
+//! `rayon` feature, `par_intern_many` interns large batches of values from rayon's thread pool.
+//!
+//! ```
+//! use interned::{Interned, SharedInterned};
+//!
+//! let local: Interned<&str> = "shared".into();
+//! let shared = SharedInterned::from_local(local);
+//! let ptr = shared.as_ptr() as usize;
+//! let other = std::thread::spawn(|| SharedInterned::from("shared").as_ptr() as usize);
+//! assert_eq!(other.join().unwrap(), ptr);
+//! ```
+
+use crate::*;
+use std::{
+    any::Any,
+    collections::HashSet,
+    sync::{Mutex, OnceLock},
+};
+
+/// Internal global pool of strings interned via [`SharedInterned`].
+static SHARED_STRS: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+/// Internal global pool of slices interned via [`SharedInterned`]: a `HashSet<&'static [T]>`
+/// per element type, keyed by the [`TypeId`] of `T`.
+static SHARED_SLICES: OnceLock<Mutex<HashMap<TypeId, Box<dyn Any + Send>>>> = OnceLock::new();
+
+/// A process-wide interned value. Unlike [`Interned`], equal values interned on different
+/// threads share the same heap address, and handles are [`Send`] and [`Sync`].
+///
+/// See the [module docs](self) for more information.
+#[derive(Copy, Clone)]
+pub struct SharedInterned<T> {
+    value: T,
+}
+
+impl SharedInterned<&'static str> {
+    /// Promotes a thread-local [`Interned<&str>`] into the global pool by re-interning its
+    /// contents there.
+    ///
+    /// Note that the resulting handle does _not_ point at the same heap memory as `local`: the
+    /// thread-local and global pools are entirely separate, so the returned pointer matches
+    /// other [`SharedInterned`] handles for the same string rather than `local.as_ptr()`.
+    pub fn from_local(local: Interned<&str>) -> Self {
+        SharedInterned::from(local.interned_str())
+    }
+
+    /// Returns the underlying globally interned string.
+    pub fn as_str(&self) -> &'static str {
+        self.value
+    }
+
+    /// Returns the underlying heap pointer where this string is stored in the global pool.
+    pub fn as_ptr(&self) -> *const () {
+        self.value.as_ptr() as *const ()
+    }
+}
+
+impl<'a> From<&'a str> for SharedInterned<&'static str> {
+    fn from(value: &'a str) -> Self {
+        let mut pool = SHARED_STRS
+            .get_or_init(Mutex::default)
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let value = match pool.get(value) {
+            Some(&existing) => existing,
+            None => {
+                let leaked: &'static str = Box::leak(value.into());
+                pool.insert(leaked);
+                leaked
+            }
+        };
+        SharedInterned { value }
+    }
+}
+
+impl PartialEq for SharedInterned<&'static str> {
+    fn eq(&self, other: &Self) -> bool {
+        // equal strings always share an address in the global pool
+        self.as_ptr() == other.as_ptr()
+    }
+}
+
+impl Eq for SharedInterned<&'static str> {}
+
+impl PartialOrd for SharedInterned<&'static str> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SharedInterned<&'static str> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.value.cmp(other.value)
+    }
+}
+
+impl Hash for SharedInterned<&'static str> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl Deref for SharedInterned<&'static str> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl std::fmt::Debug for SharedInterned<&'static str> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SharedInterned").field(&self.value).finish()
+    }
+}
+
+impl Display for SharedInterned<&'static str> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.value)
+    }
+}
+
+impl<T: Hash + Eq + Copy + Send + Sync + 'static> SharedInterned<&'static [T]> {
+    /// Promotes a thread-local [`Interned<&[T]>`](Interned) into the global pool by
+    /// re-interning its contents there. As with [`SharedInterned::from_local`] for strings,
+    /// the resulting handle does _not_ point at the same heap memory as `local`.
+    pub fn from_local_slice(local: Interned<&'static [T]>) -> Self
+    where
+        T: Staticize<Static = T>,
+    {
+        SharedInterned::from(local.interned_slice())
+    }
+
+    /// Returns the underlying globally interned slice.
+    pub fn as_slice(&self) -> &'static [T] {
+        self.value
+    }
+
+    /// Returns the underlying heap pointer where this slice is stored in the global pool.
+    ///
+    /// Zero-sized slices are never allocated, so slices of zero-sized elements (or empty
+    /// slices) of different lengths can share a pointer; compare handles with `==` instead.
+    pub fn as_ptr(&self) -> *const () {
+        self.value.as_ptr() as *const ()
+    }
+}
+
+impl<'a, T: Hash + Eq + Copy + Send + Sync + 'static> From<&'a [T]>
+    for SharedInterned<&'static [T]>
+{
+    fn from(value: &'a [T]) -> Self {
+        let mut pools = SHARED_SLICES
+            .get_or_init(Mutex::default)
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let pool = pools
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(HashSet::<&'static [T]>::new()))
+            .downcast_mut::<HashSet<&'static [T]>>()
+            .expect("shared slice pools are keyed by element type");
+        let value = match pool.get(value) {
+            Some(&existing) => existing,
+            None => {
+                let leaked: &'static [T] = Box::leak(value.into());
+                pool.insert(leaked);
+                leaked
+            }
+        };
+        SharedInterned { value }
+    }
+}
+
+impl<T: Hash + Eq + Copy + Send + Sync + 'static> PartialEq for SharedInterned<&'static [T]> {
+    fn eq(&self, other: &Self) -> bool {
+        // equal slices always share an address in the global pool, but zero-sized slices of
+        // different lengths share one too, so the length has to match as well
+        self.as_ptr() == other.as_ptr() && self.value.len() == other.value.len()
+    }
+}
+
+impl<T: Hash + Eq + Copy + Send + Sync + 'static> Eq for SharedInterned<&'static [T]> {}
+
+impl<T: Hash + Ord + Copy + Send + Sync + 'static> PartialOrd for SharedInterned<&'static [T]> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Hash + Ord + Copy + Send + Sync + 'static> Ord for SharedInterned<&'static [T]> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.value.cmp(other.value)
+    }
+}
+
+impl<T: Hash + Eq + Copy + Send + Sync + 'static> Hash for SharedInterned<&'static [T]> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<T: Hash + Eq + Copy + Send + Sync + 'static> Deref for SharedInterned<&'static [T]> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.value
+    }
+}
+
+impl<T: Hash + Eq + Copy + Send + Sync + std::fmt::Debug + 'static> std::fmt::Debug
+    for SharedInterned<&'static [T]>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SharedInterned").field(&self.value).finish()
+    }
+}
+
+/// Interns every value in `items` (strings or slices) into the global pool in parallel (on
+/// rayon's global thread pool), returning their handles in the same order as `items`. Equal
+/// values, whether within `items` or interned elsewhere, resolve to the same handle.
+///
+/// This is useful for bulk interning, which the thread-local [`Interned`] pool cannot spread
+/// across threads.
+///
+/// Requires the `rayon` feature.
+///
+/// ```
+/// use interned::{par_intern_many, SharedInterned};
+///
+/// let handles = par_intern_many(&["a", "b", "a"]);
+/// assert_eq!(handles[0], handles[2]);
+/// assert_eq!(handles[1], SharedInterned::from("b"));
+///
+/// let slices = par_intern_many(&[[1, 2].as_slice(), &[3], &[1, 2]]);
+/// assert_eq!(slices[0], slices[2]);
+/// assert_eq!(slices[1].as_slice(), [3]);
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_intern_many<T>(items: &[T]) -> Vec<SharedInterned<T::Static>>
+where
+    T: Staticize + Copy + Sync,
+    T::Static: Sized,
+    SharedInterned<T::Static>: From<T> + Send,
+{
+    use rayon::prelude::*;
+    items
+        .par_iter()
+        .map(|&item| SharedInterned::from(item))
+        .collect()
+}
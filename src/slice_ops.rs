@@ -0,0 +1,359 @@
+//! Convenience operations on interned slices (`Interned<&[T]>`) that produce new interned
+//! slices. Every slice produced by these methods is interned in its own right, meaning it is
+//! de-duplicated against (and shares heap storage with) any equal slice already interned.
+
+use crate::*;
+
+impl<T: Hash + PartialEq + Copy + Staticize<Static = T>> Interned<&'static [T]> {
+    /// Returns `true` if the underlying slice has the same contents as `other`, which is
+    /// typically a `&Vec<T>` (any `&[T]` is accepted).
+    pub fn eq_vec(&self, other: &[T]) -> bool {
+        self.interned_slice() == other
+    }
+
+    /// Returns `true` if the underlying slice has the same contents as the array `other`.
+    pub fn eq_array<const N: usize>(&self, other: &[T; N]) -> bool {
+        self.interned_slice() == other.as_slice()
+    }
+
+    /// Interns a slice consisting of `n` copies of `value`, e.g. `repeat_elem(0u8, 4)` is the
+    /// same interned slice as `[0, 0, 0, 0]`, which is handy for zeroed buffers.
+    pub fn repeat_elem(value: T, n: usize) -> Interned<&'static [T]> {
+        intern(vec![value; n].as_slice())
+    }
+
+    /// Interns the elements yielded by `iter`, allocating the buffer they are collected into
+    /// exactly once based on the iterator's (exact) length.
+    pub fn from_exact_iter<I: ExactSizeIterator<Item = T>>(iter: I) -> Interned<&'static [T]> {
+        let mut values = Vec::with_capacity(iter.len());
+        values.extend(iter);
+        intern(values.as_slice())
+    }
+
+    /// Splits the underlying slice into consecutive chunks of `size` elements (the last chunk
+    /// may be shorter), interning each chunk.
+    ///
+    /// Chunks do _not_ share the backing buffer of `self`. Each chunk is re-interned as a
+    /// standalone slice, so repeated chunk patterns (both within this slice and across other
+    /// interned slices) resolve to the same heap pointer.
+    ///
+    /// Panics if `size` is `0`.
+    pub fn chunks(&self, size: usize) -> Vec<Interned<&'static [T]>> {
+        self.interned_slice().chunks(size).map(intern).collect()
+    }
+
+    /// Splits the underlying slice into consecutive chunks of exactly `N` elements, interning
+    /// each chunk, and also returns the interned remainder (the trailing fewer-than-`N`
+    /// elements, possibly empty), as per [`slice::chunks_exact`].
+    ///
+    /// Like [`Interned::chunks`], repeated chunks resolve to the same heap pointer.
+    ///
+    /// Panics if `N` is `0`.
+    pub fn chunks_exact_interned<const N: usize>(
+        &self,
+    ) -> (Vec<Interned<&'static [T]>>, Interned<&'static [T]>) {
+        let chunks = self.interned_slice().chunks_exact(N);
+        let remainder = intern(chunks.remainder());
+        (chunks.map(intern).collect(), remainder)
+    }
+
+    /// Returns all overlapping windows of `size` elements over the underlying slice, interning
+    /// each window. If the slice is shorter than `size`, no windows are returned.
+    ///
+    /// Like [`Interned::chunks`], windows are re-interned as standalone slices rather than
+    /// sharing the backing buffer of `self`.
+    ///
+    /// Panics if `size` is `0`.
+    pub fn windows(&self, size: usize) -> Vec<Interned<&'static [T]>> {
+        self.interned_slice().windows(size).map(intern).collect()
+    }
+
+    /// Interns every length-`n` n-gram of the underlying slice, in order. This is the same as
+    /// [`Interned::windows`], named for building n-gram models over interned sequences:
+    /// repeated n-grams resolve to the same interned slice.
+    ///
+    /// Panics if `n` is `0`.
+    pub fn ngrams(&self, n: usize) -> Vec<Interned<&'static [T]>> {
+        self.windows(n)
+    }
+
+    /// Splits the underlying slice in two at `mid` (as per [`slice::split_at`]), interning
+    /// both halves. Returns `None` if `mid` is greater than the length of the slice.
+    pub fn split_at_interned(&self, mid: usize) -> Option<(Self, Self)> {
+        let slice = self.interned_slice();
+        if mid > slice.len() {
+            return None;
+        }
+        let (left, right) = slice.split_at(mid);
+        Some((intern(left), intern(right)))
+    }
+
+    /// Removes consecutive duplicate elements from the underlying slice (like
+    /// [`Vec::dedup`]) and interns the result, so that for example `[1, 1, 2, 2, 3]` and
+    /// `[1, 2, 3]` resolve to the same interned slice.
+    pub fn dedup_consecutive(&self) -> Interned<&'static [T]> {
+        let mut deduped = self.interned_slice().to_vec();
+        deduped.dedup();
+        intern(deduped.as_slice())
+    }
+
+    /// Interns a reversed copy of the underlying slice. Reversing twice yields `self` again.
+    pub fn reversed(&self) -> Interned<&'static [T]> {
+        let mut reversed = self.interned_slice().to_vec();
+        reversed.reverse();
+        intern(reversed.as_slice())
+    }
+
+    /// Interns a copy of the underlying slice rotated left by `n` elements (as per
+    /// [`slice::rotate_left`]), such that the element at index `n` comes first. `n` wraps
+    /// around the length of the slice, so any `n` is accepted.
+    pub fn rotated(&self, n: usize) -> Interned<&'static [T]> {
+        let mut rotated = self.interned_slice().to_vec();
+        if !rotated.is_empty() {
+            let len = rotated.len();
+            rotated.rotate_left(n % len);
+        }
+        intern(rotated.as_slice())
+    }
+
+    /// Applies `f` to each element of the underlying slice and interns the resulting slice, so
+    /// that e.g. mapping `[1, 2, 3]` with `|x| x * 2` resolves to the same interned slice as
+    /// `[2, 4, 6]`.
+    pub fn map_elements<U, F>(&self, f: F) -> Interned<&'static [U]>
+    where
+        U: Hash + PartialEq + Copy + Staticize<Static = U>,
+        F: Fn(&T) -> U,
+    {
+        let mapped: Vec<U> = self.interned_slice().iter().map(f).collect();
+        intern(mapped.as_slice())
+    }
+
+    /// Returns the number of leading elements the underlying slice shares with `other`, e.g.
+    /// `3` for `[1, 2, 3, 9]` and `[1, 2, 3, 8]`. Handy for building prefix-compressed
+    /// structures over interned data.
+    pub fn longest_common_prefix(&self, other: &Interned<&'static [T]>) -> usize {
+        if self.as_ptr() == other.as_ptr() {
+            return self
+                .interned_slice()
+                .len()
+                .min(other.interned_slice().len());
+        }
+        self.interned_slice()
+            .iter()
+            .zip(other.interned_slice())
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    /// Returns the number of trailing elements the underlying slice shares with `other`. See
+    /// [`Interned::longest_common_prefix`].
+    pub fn longest_common_suffix(&self, other: &Interned<&'static [T]>) -> usize {
+        if self.as_ptr() == other.as_ptr() {
+            return self
+                .interned_slice()
+                .len()
+                .min(other.interned_slice().len());
+        }
+        self.interned_slice()
+            .iter()
+            .rev()
+            .zip(other.interned_slice().iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    /// Returns `true` if the underlying slice contains an element equal to `x`.
+    pub fn contains(&self, x: &T) -> bool {
+        self.interned_slice().contains(x)
+    }
+
+    /// Returns the index of the first element of the underlying slice equal to `x`, if any.
+    pub fn position(&self, x: &T) -> Option<usize> {
+        self.interned_slice().iter().position(|item| item == x)
+    }
+
+    /// Copies the underlying slice into a fixed-size array, returning `None` unless the slice
+    /// is exactly `N` elements long. Useful for fixed-size deserialization of interned buffers.
+    pub fn to_array<const N: usize>(&self) -> Option<[T; N]> {
+        self.interned_slice().try_into().ok()
+    }
+
+    /// Binary searches the underlying (sorted) slice for `x`, as per [`slice::binary_search`].
+    ///
+    /// Since interned slices are immutable, a slice that was sorted when it was interned stays
+    /// sorted, making this a natural fit for lookups in interned tables.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.interned_slice().binary_search(x)
+    }
+
+    /// Interns the sorted intersection of this slice and `other`, both of which must already
+    /// be sorted (as is the case for canonical sorted-set representations). Elements present
+    /// in both slices are kept once per matching pair.
+    pub fn intersect(&self, other: &Interned<&'static [T]>) -> Interned<&'static [T]>
+    where
+        T: Ord,
+    {
+        if *self == *other {
+            return *self;
+        }
+        let (a, b) = (self.interned_slice(), other.interned_slice());
+        let (mut i, mut j) = (0, 0);
+        let mut result = Vec::new();
+        while i < a.len() && j < b.len() {
+            match a[i].cmp(&b[j]) {
+                std::cmp::Ordering::Less => i += 1,
+                std::cmp::Ordering::Greater => j += 1,
+                std::cmp::Ordering::Equal => {
+                    result.push(a[i]);
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        intern(result.as_slice())
+    }
+
+    /// Interns the sorted union of this slice and `other`, both of which must already be
+    /// sorted. Elements present in both slices are merged rather than repeated.
+    pub fn union(&self, other: &Interned<&'static [T]>) -> Interned<&'static [T]>
+    where
+        T: Ord,
+    {
+        if *self == *other {
+            return *self;
+        }
+        let (a, b) = (self.interned_slice(), other.interned_slice());
+        let (mut i, mut j) = (0, 0);
+        let mut result = Vec::with_capacity(a.len() + b.len());
+        while i < a.len() && j < b.len() {
+            match a[i].cmp(&b[j]) {
+                std::cmp::Ordering::Less => {
+                    result.push(a[i]);
+                    i += 1;
+                }
+                std::cmp::Ordering::Greater => {
+                    result.push(b[j]);
+                    j += 1;
+                }
+                std::cmp::Ordering::Equal => {
+                    result.push(a[i]);
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        result.extend_from_slice(&a[i..]);
+        result.extend_from_slice(&b[j..]);
+        intern(result.as_slice())
+    }
+}
+
+// Collecting interned slices concatenates their contents into a single interned slice.
+impl<T: Hash + PartialEq + Copy + Staticize<Static = T>> FromIterator<Interned<&'static [T]>>
+    for Interned<&'static [T]>
+{
+    fn from_iter<I: IntoIterator<Item = Interned<&'static [T]>>>(iter: I) -> Self {
+        let mut concatenated = Vec::new();
+        for part in iter {
+            concatenated.extend_from_slice(part.interned_slice());
+        }
+        intern(concatenated.as_slice())
+    }
+}
+
+impl Interned<&'static [u8]> {
+    /// Compares the contents of the underlying slice against `bytes` directly, without
+    /// interning `bytes`.
+    ///
+    /// Use this when matching against throwaway probes in hot loops: interning each probe just
+    /// to compare it would leak a heap allocation per distinct probe for the life of the
+    /// program.
+    pub fn eq_bytes(&self, bytes: &[u8]) -> bool {
+        self.interned_slice() == bytes
+    }
+
+    /// Iterates over the bytes of the underlying slice, yielding each byte as an interned
+    /// single-byte slice.
+    ///
+    /// There are only 256 such slices, so each is cached in a table the first time its byte
+    /// value is seen on the current thread. The same byte value always yields the same
+    /// handle, and bytes that have been seen before need no hashing or allocation.
+    pub fn byte_view(&self) -> impl Iterator<Item = Interned<&'static [u8]>> {
+        self.interned_slice()
+            .iter()
+            .map(|&byte| interned_byte_slice(byte))
+    }
+
+    /// Compresses the underlying bytes as a raw DEFLATE stream (at the default compression
+    /// level) and interns the compressed bytes, so that identical payloads share a single
+    /// compressed copy.
+    ///
+    /// Requires the `flate2` feature.
+    #[cfg(feature = "flate2")]
+    pub fn deflate(&self) -> Interned<&'static [u8]> {
+        use std::io::Write;
+        let mut encoder =
+            flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder
+            .write_all(self.interned_slice())
+            .expect("writing to a Vec cannot fail");
+        let compressed = encoder.finish().expect("writing to a Vec cannot fail");
+        intern(compressed.as_slice())
+    }
+
+    /// Decompresses the underlying bytes as a raw DEFLATE stream (as produced by
+    /// [`Interned::deflate`]) and interns the decompressed bytes. Fails if the underlying
+    /// bytes are not a valid DEFLATE stream.
+    ///
+    /// Requires the `flate2` feature.
+    #[cfg(feature = "flate2")]
+    pub fn inflate(&self) -> std::io::Result<Interned<&'static [u8]>> {
+        use std::io::Read;
+        let mut decompressed = Vec::new();
+        flate2::read::DeflateDecoder::new(self.interned_slice()).read_to_end(&mut decompressed)?;
+        Ok(intern(decompressed.as_slice()))
+    }
+}
+
+/// Returns the interned single-byte slice `[byte]` from the thread-local cached table.
+pub(crate) fn interned_byte_slice(byte: u8) -> Interned<&'static [u8]> {
+    let entry = INTERNED_BYTE_SLICES.with(|table| {
+        let slot = table.borrow()[byte as usize];
+        slot.unwrap_or_else(|| {
+            let entry = intern([byte].as_slice()).value;
+            table.borrow_mut()[byte as usize] = Some(entry);
+            entry
+        })
+    });
+    Interned {
+        _value: PhantomData,
+        value: entry,
+    }
+}
+
+impl Interned<&'static [&'static str]> {
+    /// Interns each string in the underlying slice individually, returning one
+    /// [`Interned<&str>`] handle per element. Repeated elements yield the same handle.
+    pub fn intern_elements(&self) -> Vec<Interned<&'static str>> {
+        self.interned_slice()
+            .iter()
+            .map(|element| Interned::from(*element))
+            .collect()
+    }
+}
+
+// Slices of strings are interned element-wise first, so that the interned slice only ever
+// refers to (leaked, and thus truly `'static`) interned strings rather than to the caller's
+// borrowed data.
+impl<'a, 'b> From<&'a [&'b str]> for Interned<&'static [&'static str]> {
+    fn from(value: &'a [&'b str]) -> Self {
+        let elements: Vec<&'static str> = value
+            .iter()
+            .map(|element| Interned::from(*element).interned_str())
+            .collect();
+        intern(elements.as_slice())
+    }
+}
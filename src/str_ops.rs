@@ -0,0 +1,445 @@
+//! Convenience operations on interned strings (`Interned<&str>`) that produce new interned
+//! strings. Every string produced by these methods is interned in its own right, meaning it
+//! is de-duplicated against (and shares heap storage with) any equal string already interned.
+
+use crate::*;
+use std::ffi::{CStr, CString, NulError};
+
+/// Process-wide cache of the regexes compiled by [`Interned::compiled_regex`], keyed by the
+/// address of the interned pattern. Interned strings are never freed, so an address is never
+/// reused for a different pattern.
+#[cfg(feature = "regex")]
+static COMPILED_REGEXES: std::sync::OnceLock<
+    std::sync::Mutex<HashMap<usize, &'static regex::Regex>>,
+> = std::sync::OnceLock::new();
+
+impl Interned<&'static str> {
+    /// Expands environment variable references in `s` (both `$VAR` and `${VAR}` forms, as
+    /// per [`std::env::var`]) and interns the result, so that inputs expanding to the same
+    /// string share an interned value.
+    ///
+    /// Variable names consist of ASCII letters, digits, and underscores and may not start
+    /// with a digit. Unset (or non-Unicode) variables expand to the empty string, as in a
+    /// shell. A `$` that does not start a valid reference (including an unterminated `${`) is
+    /// kept literally.
+    pub fn from_expanded(s: &str) -> Interned<&'static str> {
+        fn is_name_start(c: char) -> bool {
+            c.is_ascii_alphabetic() || c == '_'
+        }
+        fn is_name_char(c: char) -> bool {
+            c.is_ascii_alphanumeric() || c == '_'
+        }
+        let mut expanded = String::with_capacity(s.len());
+        let mut rest = s;
+        while let Some(dollar) = rest.find('$') {
+            expanded.push_str(&rest[..dollar]);
+            let after = &rest[dollar + 1..];
+            let (name, remainder) = if let Some(braced) = after.strip_prefix('{') {
+                match braced.find('}') {
+                    Some(end)
+                        if braced[..end].starts_with(is_name_start)
+                            && braced[..end].chars().all(is_name_char) =>
+                    {
+                        (&braced[..end], &braced[end + 1..])
+                    }
+                    _ => ("", after),
+                }
+            } else if after.starts_with(is_name_start) {
+                let end = after.find(|c| !is_name_char(c)).unwrap_or(after.len());
+                (&after[..end], &after[end..])
+            } else {
+                ("", after)
+            };
+            if name.is_empty() {
+                expanded.push('$');
+            } else {
+                expanded.push_str(&std::env::var(name).unwrap_or_default());
+            }
+            rest = remainder;
+        }
+        expanded.push_str(rest);
+        Interned::from(expanded.as_str())
+    }
+
+    /// Compares the underlying string against `value` directly, without interning `value`.
+    /// See [`Interned::eq_bytes`].
+    pub fn eq_str(&self, value: &str) -> bool {
+        self.interned_str() == value
+    }
+
+    /// Feeds the contents of the underlying string into `hasher`, exactly as hashing the
+    /// plain `&str` would, rather than the interner's cached hash (which is what the [`Hash`]
+    /// impl of [`Interned`] uses). Useful for keying interned strings into external indices
+    /// that depend on a specific hashing algorithm, such as bloom filters.
+    pub fn hash_with<H: Hasher>(&self, hasher: &mut H) {
+        self.interned_str().hash(hasher)
+    }
+
+    /// Returns an interned prefix of the underlying string containing at most `max_chars`
+    /// [`char`]s, always cutting on a [`char`] boundary. If the string is already short
+    /// enough, `self` is returned as-is.
+    pub fn truncate_interned(&self, max_chars: usize) -> Interned<&'static str> {
+        let value = self.interned_str();
+        match value.char_indices().nth(max_chars) {
+            Some((end, _)) => Interned::from(&value[..end]),
+            None => *self,
+        }
+    }
+
+    /// Splits the underlying string on the first occurrence of `delim`, interning the parts
+    /// before and after it. Returns [`None`] if `delim` does not occur in the string.
+    pub fn split_once_interned(
+        &self,
+        delim: char,
+    ) -> Option<(Interned<&'static str>, Interned<&'static str>)> {
+        let (before, after) = self.interned_str().split_once(delim)?;
+        Some((Interned::from(before), Interned::from(after)))
+    }
+
+    /// Returns the byte offset of the first occurrence of `sub` within the underlying string,
+    /// or [`None`] if it does not occur. Since interned strings never move, the offset can be
+    /// combined with [`Interned::as_ptr`] to build spans pointing into the interned buffer.
+    pub fn byte_offset_of(&self, sub: &str) -> Option<usize> {
+        self.interned_str().find(sub)
+    }
+
+    /// Returns `true` if the underlying string starts with the interned string `prefix`.
+    ///
+    /// A string is trivially prefixed by itself, which is detected with a pointer comparison;
+    /// otherwise this falls back to comparing bytes, bailing out early if `prefix` is longer.
+    pub fn starts_with_interned(&self, prefix: &Interned<&'static str>) -> bool {
+        if self.as_ptr() == prefix.as_ptr() {
+            return true;
+        }
+        let (value, prefix) = (self.interned_str(), prefix.interned_str());
+        prefix.len() <= value.len() && value.as_bytes()[..prefix.len()] == *prefix.as_bytes()
+    }
+
+    /// Interns the remainder of the underlying string after removing `prefix` (as per
+    /// [`str::strip_prefix`]). Returns [`None`] if the string does not start with `prefix`.
+    pub fn strip_prefix_interned(&self, prefix: &str) -> Option<Interned<&'static str>> {
+        self.interned_str().strip_prefix(prefix).map(Interned::from)
+    }
+
+    /// Interns the remainder of the underlying string after removing `suffix` (as per
+    /// [`str::strip_suffix`]). Returns [`None`] if the string does not end with `suffix`.
+    pub fn strip_suffix_interned(&self, suffix: &str) -> Option<Interned<&'static str>> {
+        self.interned_str().strip_suffix(suffix).map(Interned::from)
+    }
+
+    /// Replaces all matches of `from` with `to` (as per [`str::replace`]) and interns the
+    /// result. If the replacement leaves the string unchanged, the result is `self`.
+    pub fn replace_interned(&self, from: &str, to: &str) -> Interned<&'static str> {
+        let value = self.interned_str();
+        if !value.contains(from) {
+            return *self;
+        }
+        Interned::from(value.replace(from, to).as_str())
+    }
+
+    /// Encodes the underlying string as UTF-16 (as per [`str::encode_utf16`]) and interns the
+    /// resulting `u16` slice.
+    pub fn to_utf16(&self) -> Interned<&'static [u16]> {
+        let encoded: Vec<u16> = self.interned_str().encode_utf16().collect();
+        Interned::from(encoded.as_slice())
+    }
+
+    /// Iterates over the UTF-8 bytes of the underlying string, yielding each byte as an
+    /// interned single-byte slice. See [`Interned::byte_view`].
+    pub fn bytes_interned(&self) -> impl Iterator<Item = Interned<&'static [u8]>> {
+        self.interned_str()
+            .bytes()
+            .map(slice_ops::interned_byte_slice)
+    }
+
+    /// Iterates over the [`char`]s of the underlying string along with their byte offsets (as
+    /// per [`str::char_indices`]), yielding each [`char`] as an [`Interned<char>`].
+    pub fn char_indices_interned(&self) -> impl Iterator<Item = (usize, Interned<char>)> {
+        self.interned_str()
+            .char_indices()
+            .map(|(offset, c)| (offset, Interned::from(c)))
+    }
+
+    /// Computes the Levenshtein (edit) distance between the underlying string and that of
+    /// `other`, counted in [`char`]s: the minimum number of single-[`char`] insertions,
+    /// deletions, and substitutions needed to turn one into the other. Handles to the same
+    /// interned string short-circuit to `0`.
+    pub fn levenshtein(&self, other: &Interned<&'static str>) -> usize {
+        if self.as_ptr() == other.as_ptr() {
+            return 0;
+        }
+        let a: Vec<char> = self.interned_str().chars().collect();
+        let b: Vec<char> = other.interned_str().chars().collect();
+        // classic dynamic programming over a single row of the distance matrix
+        let mut row: Vec<usize> = (0..=b.len()).collect();
+        for (i, ca) in a.iter().enumerate() {
+            let mut diagonal = row[0];
+            row[0] = i + 1;
+            for (j, cb) in b.iter().enumerate() {
+                let substitution = diagonal + usize::from(ca != cb);
+                diagonal = row[j + 1];
+                row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+            }
+        }
+        row[b.len()]
+    }
+
+    /// Interns the [`OsStr`] view of the underlying string (as per [`OsStr::new`]).
+    pub fn to_os_str(&self) -> Interned<&'static OsStr> {
+        Interned::from(OsStr::new(self.interned_str()))
+    }
+
+    /// Converts the underlying string into a nul-terminated C string for FFI, failing if it
+    /// contains an interior nul byte (as per [`CString::new`]).
+    ///
+    /// The nul-terminated bytes are interned as an `&[u8]`, so repeated conversions of equal
+    /// strings share the same `'static` C string rather than allocating a new one each time.
+    /// The C string is returned as a plain `&'static CStr` rather than an
+    /// `Interned<&'static CStr>`, since [`CStr`] does not implement [`Staticize`].
+    pub fn to_cstring(&self) -> Result<&'static CStr, NulError> {
+        let cstring = CString::new(self.interned_str())?;
+        let bytes: Interned<&'static [u8]> = intern(cstring.as_bytes_with_nul());
+        Ok(CStr::from_bytes_with_nul(bytes.interned_slice())
+            .expect("interned bytes come from a valid CString"))
+    }
+
+    /// Interns the underlying string left-padded with `fill` up to `width` [`char`]s, e.g. for
+    /// zero-padding numbers. Strings already at least `width` chars long are returned as-is.
+    pub fn pad_start(&self, width: usize, fill: char) -> Interned<&'static str> {
+        let s = self.interned_str();
+        let missing = width.saturating_sub(s.chars().count());
+        if missing == 0 {
+            return *self;
+        }
+        let mut padded: String = std::iter::repeat_n(fill, missing).collect();
+        padded.push_str(s);
+        Interned::from(padded.as_str())
+    }
+
+    /// Interns the underlying string right-padded with `fill` up to `width` [`char`]s. Strings
+    /// already at least `width` chars long are returned as-is.
+    pub fn pad_end(&self, width: usize, fill: char) -> Interned<&'static str> {
+        let s = self.interned_str();
+        let missing = width.saturating_sub(s.chars().count());
+        if missing == 0 {
+            return *self;
+        }
+        let mut padded = s.to_string();
+        padded.extend(std::iter::repeat_n(fill, missing));
+        Interned::from(padded.as_str())
+    }
+
+    /// Interns the underlying string with its [`char`]s in reverse order.
+    ///
+    /// The reversal is memoized in both directions at once: reversing `self` also records
+    /// `self` as the reversal of the result, so reversing back is a cache hit that returns
+    /// the original handle without rescanning the string.
+    pub fn reversed(&self) -> Interned<&'static str> {
+        const SCOPE: &str = "Interned::<&str>::reversed";
+        let original = *self;
+        Memoized::<u64, &str>::from_once(SCOPE, self.token(), |_| {
+            let reversed: String = original.interned_str().chars().rev().collect();
+            let reversed = Interned::from(reversed.as_str());
+            Memoized::<u64, &str>::from_once(SCOPE, reversed.token(), |_| original);
+            reversed
+        })
+        .interned()
+    }
+
+    /// Splits the underlying string into lines (as per [`str::lines`], so line endings are
+    /// stripped) and interns each line as an [`InStr`]. Since identical lines share a handle,
+    /// a line cache keyed by these handles only sees new pointers for lines that changed.
+    pub fn intern_lines(&self) -> Vec<InStr> {
+        self.interned_str().lines().map(InStr::from).collect()
+    }
+
+    /// Keeps only the ASCII alphanumeric characters of the underlying string (as per
+    /// [`char::is_ascii_alphanumeric`]) and interns the result, e.g. for sanitizing
+    /// identifiers. Strings that are already purely ASCII alphanumeric are returned as-is.
+    pub fn retain_ascii_alnum(&self) -> Interned<&'static str> {
+        let value = self.interned_str();
+        if value.bytes().all(|byte| byte.is_ascii_alphanumeric()) {
+            return *self;
+        }
+        let retained: String = value.chars().filter(char::is_ascii_alphanumeric).collect();
+        Interned::from(retained.as_str())
+    }
+
+    /// Converts Windows (`\r\n`) and classic Mac (`\r`) line endings in the underlying
+    /// string to `\n` and interns the result, so the same logical text from different
+    /// platforms dedupes. Strings without a `\r` are returned as-is.
+    pub fn normalize_newlines(&self) -> Interned<&'static str> {
+        let value = self.interned_str();
+        if !value.contains('\r') {
+            return *self;
+        }
+        Interned::from(value.replace("\r\n", "\n").replace('\r', "\n").as_str())
+    }
+
+    /// Converts Windows-style path separators (`\`) in the underlying string to `/` and
+    /// interns the result, so the same logical path written on different platforms dedupes.
+    /// Strings without a `\` are returned as-is.
+    pub fn normalize_path_seps(&self) -> Interned<&'static str> {
+        let value = self.interned_str();
+        if !value.contains('\\') {
+            return *self;
+        }
+        Interned::from(value.replace('\\', "/").as_str())
+    }
+
+    /// Splits the underlying string into its extended grapheme clusters (user-perceived
+    /// characters, including any combining marks), interning each cluster. Identical clusters,
+    /// whether within this string or across others, resolve to the same heap pointer.
+    ///
+    /// Requires the `unicode-segmentation` feature.
+    #[cfg(feature = "unicode-segmentation")]
+    pub fn graphemes(&self) -> Vec<Interned<&'static str>> {
+        use unicode_segmentation::UnicodeSegmentation;
+        self.interned_str()
+            .graphemes(true)
+            .map(Interned::from)
+            .collect()
+    }
+
+    /// Interns the Title Case form of the underlying string (as per [`heck::ToTitleCase`]),
+    /// e.g. `"hello_world"` becomes `"Hello World"`.
+    ///
+    /// Requires the `heck` feature.
+    #[cfg(feature = "heck")]
+    pub fn to_title_case(&self) -> Interned<&'static str> {
+        use heck::ToTitleCase;
+        Interned::from(self.interned_str().to_title_case().as_str())
+    }
+
+    /// Interns the snake_case form of the underlying string (as per [`heck::ToSnakeCase`]),
+    /// e.g. `"HelloWorld"` becomes `"hello_world"`.
+    ///
+    /// Requires the `heck` feature.
+    #[cfg(feature = "heck")]
+    pub fn to_snake_case(&self) -> Interned<&'static str> {
+        use heck::ToSnakeCase;
+        Interned::from(self.interned_str().to_snake_case().as_str())
+    }
+
+    /// Interns the lowerCamelCase form of the underlying string (as per
+    /// [`heck::ToLowerCamelCase`]), e.g. `"hello_world"` becomes `"helloWorld"`.
+    ///
+    /// Requires the `heck` feature.
+    #[cfg(feature = "heck")]
+    pub fn to_camel_case(&self) -> Interned<&'static str> {
+        use heck::ToLowerCamelCase;
+        Interned::from(self.interned_str().to_lower_camel_case().as_str())
+    }
+
+    /// Interns the Unicode Normalization Form C (canonical composition) of the underlying
+    /// string, so that strings which differ only in how their characters are encoded (e.g.
+    /// precomposed `"é"` vs `"e"` followed by a combining acute accent) share an interned value.
+    ///
+    /// Requires the `unicode-normalization` feature.
+    #[cfg(feature = "unicode-normalization")]
+    pub fn to_nfc(&self) -> Interned<&'static str> {
+        use unicode_normalization::UnicodeNormalization;
+        Interned::from(self.interned_str().nfc().collect::<String>().as_str())
+    }
+
+    /// Interns the Unicode Normalization Form D (canonical decomposition) of the underlying
+    /// string. See [`Interned::to_nfc`].
+    ///
+    /// Requires the `unicode-normalization` feature.
+    #[cfg(feature = "unicode-normalization")]
+    pub fn to_nfd(&self) -> Interned<&'static str> {
+        use unicode_normalization::UnicodeNormalization;
+        Interned::from(self.interned_str().nfd().collect::<String>().as_str())
+    }
+
+    /// Strips combining diacritical marks from the underlying string (by decomposing it as
+    /// per [`Interned::to_nfd`], dropping the marks, and recomposing the rest) and interns the
+    /// result, so that for example `"café"` and `"cafe"` dedupe to the same folded string for
+    /// accent-insensitive search. ASCII strings are returned as-is.
+    ///
+    /// Requires the `unicode-normalization` feature.
+    #[cfg(feature = "unicode-normalization")]
+    pub fn fold_diacritics(&self) -> Interned<&'static str> {
+        use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+        let value = self.interned_str();
+        if value.is_ascii() {
+            return *self;
+        }
+        let folded: String = value
+            .nfd()
+            .filter(|c| !is_combining_mark(*c))
+            .nfc()
+            .collect();
+        Interned::from(folded.as_str())
+    }
+
+    /// Percent-encodes the underlying string for use as a URL component and interns the
+    /// result. Every byte other than the RFC 3986 unreserved characters (ASCII alphanumerics
+    /// and `-`, `.`, `_`, `~`) is encoded, e.g. `"a b"` becomes `"a%20b"`.
+    ///
+    /// Requires the `percent-encoding` feature.
+    #[cfg(feature = "percent-encoding")]
+    pub fn percent_encode(&self) -> Interned<&'static str> {
+        use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+        const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+            .remove(b'-')
+            .remove(b'.')
+            .remove(b'_')
+            .remove(b'~');
+        let encoded = utf8_percent_encode(self.interned_str(), COMPONENT).to_string();
+        Interned::from(encoded.as_str())
+    }
+
+    /// Decodes percent-encoded sequences (`%XX`) in the underlying string and interns the
+    /// result, failing if the decoded bytes are not valid UTF-8. Malformed sequences are
+    /// kept literally. Strings without a `%` are returned as-is.
+    ///
+    /// Requires the `percent-encoding` feature.
+    #[cfg(feature = "percent-encoding")]
+    pub fn percent_decode(&self) -> Result<Interned<&'static str>, std::str::Utf8Error> {
+        let value = self.interned_str();
+        if !value.contains('%') {
+            return Ok(*self);
+        }
+        let decoded = percent_encoding::percent_decode_str(value).decode_utf8()?;
+        Ok(Interned::from(&*decoded))
+    }
+
+    /// Compiles the underlying string as a [`regex::Regex`], returning the cached regex if
+    /// this interned pattern was compiled before. Compiled regexes are leaked and live for the
+    /// rest of the process.
+    ///
+    /// The cache is shared by all threads but keyed by the pattern's address, and since each
+    /// thread has its own interning pool, the same pattern interned on two threads is compiled
+    /// (at most) once per thread. Patterns that fail to compile are not cached.
+    ///
+    /// Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    pub fn compiled_regex(&self) -> Result<&'static regex::Regex, regex::Error> {
+        let cache = COMPILED_REGEXES.get_or_init(Default::default);
+        let key = self.as_ptr() as usize;
+        let lock = || {
+            cache
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+        };
+        if let Some(&compiled) = lock().get(&key) {
+            return Ok(compiled);
+        }
+        // compile without holding the lock, so other patterns aren't held up in the meantime
+        let compiled = regex::Regex::new(self.interned_str())?;
+        Ok(*lock()
+            .entry(key)
+            .or_insert_with(|| Box::leak(Box::new(compiled))))
+    }
+}
+
+impl Interned<char> {
+    /// Interns the one-[`char`] string containing the underlying [`char`], so that for example
+    /// `Interned::from('a').to_str()` is the same handle as `Interned::from("a")`. Handy in
+    /// lexers, which often deal in single-character tokens.
+    pub fn to_str(&self) -> Interned<&'static str> {
+        let mut buf = [0; 4];
+        Interned::from(&*self.interned_value().encode_utf8(&mut buf))
+    }
+}
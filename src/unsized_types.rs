@@ -4,6 +4,11 @@ use core::ops::Deref;
 use std::ffi::OsString;
 use std::path::{Path, PathBuf};
 
+thread_local! {
+    /// Reused buffer that [`InStr::from_fmt`] formats into before interning.
+    static FMT_BUFFER: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
 /// A convenience abstraction around [`Interned<&'static str>`] with some extra [`From`] impls
 /// and other convenience functions. This should be your go-to type if you want to work with
 /// interned strings.
@@ -38,6 +43,67 @@ impl InStr {
     pub fn as_ptr(&self) -> *const () {
         self.0.as_ptr()
     }
+
+    /// Returns the UTF-8 bytes of the underlying interned string. Unlike going through
+    /// [`Deref`], the returned slice has an honest `'static` lifetime.
+    pub fn as_bytes(&self) -> &'static [u8] {
+        self.0.interned_str().as_bytes()
+    }
+
+    /// Returns the length of the underlying interned string in bytes (not [`char`]s).
+    pub fn byte_len(&self) -> usize {
+        self.0.interned_str().len()
+    }
+
+    /// Returns `true` if the underlying interned string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.interned_str().is_empty()
+    }
+
+    /// Formats `value` via its [`Display`] impl and interns the result, so for example
+    /// `InStr::display(42)` is the same as `InStr::from("42")`.
+    ///
+    /// This is a named constructor rather than a blanket [`From`] impl, since the latter would
+    /// conflict with the existing [`From`] impls of [`InStr`].
+    pub fn display(value: impl Display) -> InStr {
+        InStr::from(value.to_string())
+    }
+
+    /// Formats `args` (as produced by [`format_args!`]) and interns the result.
+    ///
+    /// Unlike [`InStr::display`], the text is written into a reused thread-local buffer
+    /// rather than a fresh [`String`], so formatting a value that is already interned does not
+    /// allocate at all.
+    ///
+    /// ```
+    /// use interned::InStr;
+    ///
+    /// let id = 7;
+    /// assert_eq!(InStr::from_fmt(format_args!("item-{id}")), "item-7");
+    /// ```
+    pub fn from_fmt(args: std::fmt::Arguments) -> InStr {
+        use std::fmt::Write;
+        if let Some(literal) = args.as_str() {
+            return InStr::from(literal);
+        }
+        FMT_BUFFER.with(|buffer| match buffer.try_borrow_mut() {
+            Ok(mut buffer) => {
+                buffer.clear();
+                buffer
+                    .write_fmt(args)
+                    .expect("formatting into a String cannot fail");
+                InStr::from(buffer.as_str())
+            }
+            // `args` itself called `from_fmt` while being formatted, so use a fresh buffer
+            Err(_) => InStr::from(std::fmt::format(args)),
+        })
+    }
+
+    /// Converts this [`InStr`] into an [`InOsStr`] holding the [`OsStr`] view of the same
+    /// string.
+    pub fn to_in_os_str(&self) -> InOsStr {
+        InOsStr(self.0.to_os_str())
+    }
 }
 
 impl Display for InStr {
@@ -70,7 +136,7 @@ impl From<Interned<&'static str>> for InStr {
     }
 }
 
-impl<'a> From<InStr> for &'a str {
+impl From<InStr> for &str {
     fn from(value: InStr) -> Self {
         value.0.interned_str()
     }
@@ -94,6 +160,22 @@ impl PartialEq<String> for InStr {
     }
 }
 
+impl PartialEq<Interned<&'static str>> for InStr {
+    fn eq(&self, other: &Interned<&'static str>) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Note: with this impl in place, [`Interned<&str>`](Interned) has more than one
+/// [`PartialEq`] impl, so a comparison such as `interned == "x".into()` no longer infers
+/// its target type. Spell out `Interned::from("x")` (or `InStr::from("x")`) instead. The
+/// same applies to the [`InOsStr`] and [`InPath`] counterparts.
+impl PartialEq<InStr> for Interned<&'static str> {
+    fn eq(&self, other: &InStr) -> bool {
+        *self == other.0
+    }
+}
+
 impl PartialOrd<&str> for InStr {
     fn partial_cmp(&self, other: &&str) -> Option<std::cmp::Ordering> {
         self.0.interned_str().partial_cmp(*other)
@@ -108,6 +190,73 @@ impl Deref for InStr {
     }
 }
 
+/// Builds up a string incrementally and interns it as an [`InStr`], optionally taking
+/// interned [snapshots](InStrBuilder::snapshot) of the contents so far along the way.
+///
+/// Only snapshots (and the final result) are interned; the contents in between live in an
+/// ordinary growable buffer.
+///
+/// ```
+/// use interned::{InStr, InStrBuilder};
+///
+/// let mut builder = InStrBuilder::new();
+/// builder.push_str("ab");
+/// let ab = builder.snapshot();
+/// builder.push('c');
+/// assert_eq!(ab, "ab");
+/// assert_eq!(builder.finish(), InStr::from("abc"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct InStrBuilder {
+    buffer: String,
+}
+
+impl InStrBuilder {
+    /// Creates a new, empty [`InStrBuilder`].
+    pub fn new() -> InStrBuilder {
+        InStrBuilder::default()
+    }
+
+    /// Creates a new, empty [`InStrBuilder`] with room for at least `capacity` bytes.
+    pub fn with_capacity(capacity: usize) -> InStrBuilder {
+        InStrBuilder {
+            buffer: String::with_capacity(capacity),
+        }
+    }
+
+    /// Appends `s` to the contents of this builder.
+    pub fn push_str(&mut self, s: &str) {
+        self.buffer.push_str(s);
+    }
+
+    /// Appends `c` to the contents of this builder.
+    pub fn push(&mut self, c: char) {
+        self.buffer.push(c);
+    }
+
+    /// Returns the (not yet interned) contents of this builder so far.
+    pub fn as_str(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Interns the contents of this builder so far, without consuming the builder.
+    pub fn snapshot(&self) -> InStr {
+        InStr::from(self.buffer.as_str())
+    }
+
+    /// Interns the final contents of this builder.
+    pub fn finish(self) -> InStr {
+        self.snapshot()
+    }
+}
+
+impl std::fmt::Write for InStrBuilder {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
 /// A convenience abstraction around [`Interned<&'static OsStr>`] with some extra [`From`] impls
 /// and other convenience functions. This should be your go-to type if you want to work with
 /// interned [`OsStr`]s and/or [`OsString`]s.
@@ -175,7 +324,7 @@ impl From<Interned<&'static OsStr>> for InOsStr {
     }
 }
 
-impl<'a> From<InOsStr> for &'a OsStr {
+impl From<InOsStr> for &OsStr {
     fn from(value: InOsStr) -> Self {
         value.0.interned_os_str()
     }
@@ -199,6 +348,18 @@ impl PartialEq<OsString> for InOsStr {
     }
 }
 
+impl PartialEq<Interned<&'static OsStr>> for InOsStr {
+    fn eq(&self, other: &Interned<&'static OsStr>) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<InOsStr> for Interned<&'static OsStr> {
+    fn eq(&self, other: &InOsStr) -> bool {
+        *self == other.0
+    }
+}
+
 impl PartialOrd<&OsStr> for InOsStr {
     fn partial_cmp(&self, other: &&OsStr) -> Option<std::cmp::Ordering> {
         self.0.interned_os_str().partial_cmp(*other)
@@ -291,7 +452,7 @@ impl From<Interned<&'static Path>> for InPath {
     }
 }
 
-impl<'a> From<InPath> for &'a Path {
+impl From<InPath> for &Path {
     fn from(value: InPath) -> Self {
         value.0.interned_path()
     }
@@ -321,6 +482,18 @@ impl PartialEq<Path> for InPath {
     }
 }
 
+impl PartialEq<Interned<&'static Path>> for InPath {
+    fn eq(&self, other: &Interned<&'static Path>) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<InPath> for Interned<&'static Path> {
+    fn eq(&self, other: &InPath) -> bool {
+        *self == other.0
+    }
+}
+
 impl PartialOrd<&Path> for InPath {
     fn partial_cmp(&self, other: &&Path) -> Option<std::cmp::Ordering> {
         self.0.interned_path().partial_cmp(*other)
@@ -0,0 +1,67 @@
+//! Contains [`Uuid`], a thin wrapper around [`uuid::Uuid`] that can be used with
+//! [`Interned`] and [`Memoized`]. Enabled by the `uuid` feature.
+//!
+//! [`uuid::Uuid`] cannot be used with [`Interned`] directly because neither
+//! [`Staticize`] nor [`uuid::Uuid`] is local to this crate, so [`Uuid`] is
+//! provided instead. It is `#[repr(transparent)]`, converts freely to and from
+//! [`uuid::Uuid`], and dereferences to it.
+//!
+//! ```
+//! use interned::{uuids::Uuid, Interned};
+//!
+//! let id = uuid::Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8);
+//! let a: Interned<Uuid> = id.into();
+//! let b: Interned<Uuid> = id.into();
+//! assert_eq!(a.as_ptr(), b.as_ptr());
+//! assert_eq!(uuid::Uuid::from(a), id);
+//! ```
+
+use crate::*;
+
+/// A `#[repr(transparent)]` wrapper around [`uuid::Uuid`] implementing
+/// [`DataType`] and [`Staticize`], allowing UUIDs to be interned and memoized.
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Uuid(pub uuid::Uuid);
+
+derive_staticize!(Uuid);
+unsafe_impl_data_type!(Uuid, Value);
+derive_from_interned_impl_value!(Uuid);
+
+impl Display for Uuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Deref for Uuid {
+    type Target = uuid::Uuid;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<uuid::Uuid> for Uuid {
+    fn from(value: uuid::Uuid) -> Self {
+        Uuid(value)
+    }
+}
+
+impl From<Uuid> for uuid::Uuid {
+    fn from(value: Uuid) -> Self {
+        value.0
+    }
+}
+
+impl From<uuid::Uuid> for Interned<Uuid> {
+    fn from(value: uuid::Uuid) -> Self {
+        Interned::from(Uuid(value))
+    }
+}
+
+impl From<Interned<Uuid>> for uuid::Uuid {
+    fn from(value: Interned<Uuid>) -> Self {
+        value.interned_value().0
+    }
+}
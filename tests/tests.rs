@@ -177,7 +177,7 @@ fn test_interned_showcase() {
     assert_eq!(a.as_ptr(), b.as_ptr());
     assert_ne!(b.as_ptr(), c.as_ptr());
     let d: Interned<&str> = "asdf".into();
-    assert_ne!(d, "fdsa".into());
+    assert_ne!(d, Interned::from("fdsa"));
     assert_eq!(Interned::from("asdf"), d);
     let e = Interned::from([1, 2, 3, 4, 5].as_slice());
     let f = InStr::from("abc");
@@ -217,7 +217,7 @@ fn test_interned_into() {
     assert_ne!(a, c);
     assert_ne!(c, b);
     let _number: i32 = b.into();
-    assert_eq!(32, b.into());
+    assert_eq!(32, i32::from(b));
 }
 
 #[test]
@@ -337,3 +337,2099 @@ fn test_static_slice() {
         &[true, false, true, false, true, false]
     );
 }
+
+#[test]
+fn test_interned_slice_ord_differing_lengths() {
+    use std::collections::BTreeMap;
+
+    let a = Interned::from([1, 2].as_slice());
+    let b = Interned::from([1, 2, 3].as_slice());
+    let c = Interned::from([1, 3].as_slice());
+    let d = Interned::from([2].as_slice());
+    let e = Interned::from([0i32; 0].as_slice());
+    assert!(a < b);
+    assert!(c < d);
+    assert!(e < a);
+    assert!(b < c);
+    assert_eq!(a.cmp(&a), std::cmp::Ordering::Equal);
+    assert_eq!(a.partial_cmp(&b), Some(std::cmp::Ordering::Less));
+    assert_eq!(d.partial_cmp(&c), Some(std::cmp::Ordering::Greater));
+
+    let mut map = BTreeMap::new();
+    map.insert(d, "d");
+    map.insert(b, "b");
+    map.insert(c, "c");
+    map.insert(e, "e");
+    map.insert(a, "a");
+    assert_eq!(
+        map.values().copied().collect::<Vec<_>>(),
+        vec!["e", "a", "b", "c", "d"]
+    );
+}
+
+#[test]
+fn test_lowercase_interned() {
+    use std::collections::HashSet;
+
+    let a = LowercaseInterned::from("Foo");
+    let b = LowercaseInterned::from("foo");
+    let c = LowercaseInterned::from(String::from("FOO"));
+    let d = LowercaseInterned::from("bar");
+    assert_eq!(a, b);
+    assert_eq!(b, c);
+    assert_ne!(a, d);
+    assert_eq!(a.original(), "Foo");
+    assert_eq!(b.original(), "foo");
+    assert_eq!(c.original(), "FOO");
+    assert_ne!(a.original(), b.original());
+    assert_eq!(a.lowercased(), "foo");
+    assert_eq!(
+        a.interned_lowercased().as_ptr(),
+        c.interned_lowercased().as_ptr()
+    );
+    assert_eq!(a.to_string(), "Foo");
+    assert!(d < a);
+    let set: HashSet<LowercaseInterned> = [a, b, c, d].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    // lowercasing is not full case folding
+    assert_ne!(LowercaseInterned::from("ß"), LowercaseInterned::from("SS"));
+    assert_eq!(LowercaseInterned::from("Ǆ"), LowercaseInterned::from("ǆ"));
+}
+
+#[test]
+fn test_interned_slice_chunks_and_windows() {
+    let a: Interned<&[i32]> = Interned::from([1, 2, 1, 2].as_slice());
+    let chunks = a.chunks(2);
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0], chunks[1]);
+    assert_eq!(chunks[0].as_ptr(), chunks[1].as_ptr());
+    assert_eq!(chunks[0].interned_slice(), &[1, 2]);
+    assert_eq!(chunks[0], Interned::from([1, 2].as_slice()));
+
+    let b = Interned::from([1, 2, 3, 4].as_slice());
+    let chunks = b.chunks(3);
+    assert_eq!(chunks[0].interned_slice(), &[1, 2, 3]);
+    assert_eq!(chunks[1].interned_slice(), &[4]);
+
+    let windows = a.windows(2);
+    assert_eq!(windows.len(), 3);
+    assert_eq!(windows[0].as_ptr(), windows[2].as_ptr());
+    assert_eq!(windows[1].interned_slice(), &[2, 1]);
+    assert!(a.windows(5).is_empty());
+}
+
+#[test]
+fn test_interned_bytes_from_cow_and_vec() {
+    use std::borrow::Cow;
+
+    let a: Interned<&[u8]> = Interned::from([7u8, 8, 9].as_slice());
+    let borrowed: Interned<&[u8]> = Cow::Borrowed([7u8, 8, 9].as_slice()).into();
+    let owned: Interned<&[u8]> = Cow::<[u8]>::Owned(vec![7u8, 8, 9]).into();
+    let from_vec: Interned<&[u8]> = vec![7u8, 8, 9].into();
+    assert_eq!(a, borrowed);
+    assert_eq!(a, owned);
+    assert_eq!(a, from_vec);
+    assert_eq!(a.as_ptr(), borrowed.as_ptr());
+    assert_eq!(a.as_ptr(), owned.as_ptr());
+    assert_eq!(a.as_ptr(), from_vec.as_ptr());
+    let other: Interned<&[u8]> = vec![9u8, 8, 7].into();
+    assert_ne!(a, other);
+    assert_eq!(other.interned_slice(), &[9, 8, 7]);
+}
+
+#[test]
+fn test_interned_ptr_cmp() {
+    use std::cmp::Ordering;
+
+    let a: Interned<&str> = "ptr_cmp a".into();
+    let b: Interned<&str> = "ptr_cmp b".into();
+    let c: Interned<&str> = "ptr_cmp c".into();
+    let a2: Interned<&str> = "ptr_cmp a".into();
+    assert_eq!(a.ptr_cmp(&a2), Ordering::Equal);
+    assert_ne!(a.ptr_cmp(&b), Ordering::Equal);
+    assert_eq!(a.ptr_cmp(&b), b.ptr_cmp(&a).reverse());
+    let mut values = vec![c, a, b, a2];
+    values.sort_by(|x, y| x.ptr_cmp(y));
+    for pair in values.windows(2) {
+        assert_ne!(pair[0].ptr_cmp(&pair[1]), Ordering::Greater);
+    }
+    values.dedup();
+    assert_eq!(values.len(), 3);
+    let mut resorted = values.clone();
+    resorted.reverse();
+    resorted.sort_by(|x, y| x.ptr_cmp(y));
+    assert_eq!(values, resorted);
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn test_interned_decimal() {
+    use interned::decimal::Decimal;
+    use std::str::FromStr;
+
+    let dec = |s: &str| rust_decimal::Decimal::from_str(s).unwrap();
+    let a: Interned<Decimal> = dec("10.25").into();
+    let b: Interned<Decimal> = Interned::from(Decimal(dec("10.25")));
+    let c: Interned<Decimal> = dec("3.5").into();
+    assert_eq!(a, b);
+    assert_eq!(a.as_ptr(), b.as_ptr());
+    assert_ne!(a, c);
+    assert!(c < a);
+    assert_eq!(**a.interned_value(), dec("10.25"));
+    assert_eq!(a.to_string(), "10.25");
+    // the scale read back never depends on which equal value was interned first
+    let wide: Interned<Decimal> = dec("10.250").into();
+    assert_ne!(wide, a);
+    assert_eq!(wide.to_string(), "10.250");
+    assert_eq!(wide.scale(), 3);
+    assert_eq!(a.scale(), 2);
+    assert_eq!(**wide.interned_value(), **a.interned_value());
+    assert!(a < wide);
+    let m = Memoized::from("decimal scope", (1, 4), |(n, d): (i64, i64)| {
+        Decimal(rust_decimal::Decimal::from(n) / rust_decimal::Decimal::from(d)).into()
+    });
+    assert_eq!(*m.as_value(), Decimal(dec("0.25")));
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn test_interned_uuid() {
+    use interned::uuids::Uuid;
+
+    let id = uuid::Uuid::from_u128(0x936d_a01f_9abd_4d9d_80c7_02af_85c8_22a8);
+    let a: Interned<Uuid> = id.into();
+    let b: Interned<Uuid> = Interned::from(Uuid(uuid::Uuid::from_bytes(*id.as_bytes())));
+    let c: Interned<Uuid> = uuid::Uuid::nil().into();
+    assert_eq!(a, b);
+    assert_eq!(a.as_ptr(), b.as_ptr());
+    assert_ne!(a, c);
+    assert!(c < a);
+    assert_eq!(uuid::Uuid::from(a), id);
+    assert_eq!(**a.interned_value(), id);
+    assert_eq!(a.to_string(), "936da01f-9abd-4d9d-80c7-02af85c822a8");
+}
+
+#[cfg(feature = "percent-encoding")]
+#[test]
+fn test_interned_percent_encoding() {
+    let plain: Interned<&str> = "a b".into();
+    let encoded = plain.percent_encode();
+    assert_eq!(encoded.as_ptr(), Interned::from("a%20b").as_ptr());
+    assert_eq!(encoded.percent_decode().unwrap().as_ptr(), plain.as_ptr());
+    assert_eq!(plain.percent_decode().unwrap().as_ptr(), plain.as_ptr());
+    let unreserved: Interned<&str> = "A-z_0.9~".into();
+    assert_eq!(unreserved.percent_encode().as_ptr(), unreserved.as_ptr());
+    let multibyte: Interned<&str> = "é/?".into();
+    assert_eq!(multibyte.percent_encode().interned_str(), "%C3%A9%2F%3F");
+    let invalid: Interned<&str> = "%FF".into();
+    assert!(invalid.percent_decode().is_err());
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_mmap_interner_persists_across_opens() {
+    use interned::mmap::MmapInterner;
+
+    let path = std::env::temp_dir().join(format!(
+        "interned-test-mmap-{}-{:?}.bin",
+        std::process::id(),
+        std::thread::current().id()
+    ));
+    let _ = std::fs::remove_file(&path);
+
+    let interner = unsafe { MmapInterner::open(&path, 4096) }.unwrap();
+    let hello = interner.intern("hello").unwrap();
+    let world = interner.intern("world").unwrap();
+    assert_eq!(interner.intern("hello").unwrap().as_ptr(), hello.as_ptr());
+    assert_eq!(interner.lookup("world").unwrap().as_ptr(), world.as_ptr());
+    assert_eq!(interner.lookup("missing"), None);
+    let used = interner.bytes_used();
+    drop(interner);
+
+    let reopened = unsafe { MmapInterner::open(&path, 4096) }.unwrap();
+    assert_eq!(reopened.bytes_used(), used);
+    assert_eq!(reopened.lookup("hello"), Some("hello"));
+    assert_eq!(reopened.intern("world").unwrap(), "world");
+    assert_eq!(reopened.bytes_used(), used);
+    // strings from the first mapping stay valid, since mappings are never unmapped
+    assert_eq!(hello, "hello");
+
+    let big = "x".repeat(8192);
+    let err = reopened.intern(&big).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::StorageFull);
+    drop(reopened);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_mmap_interner_skips_crashed_writer() {
+    use interned::mmap::MmapInterner;
+    use std::io::{Read, Seek, SeekFrom, Write};
+
+    let path = std::env::temp_dir().join(format!(
+        "interned-test-mmap-crash-{}-{:?}.bin",
+        std::process::id(),
+        std::thread::current().id()
+    ));
+    let _ = std::fs::remove_file(&path);
+
+    let interner = unsafe { MmapInterner::open(&path, 4096) }.unwrap();
+    interner.intern("before").unwrap();
+    // simulate a writer that reserved 8 bytes, marked them pending, and then crashed
+    let mut file = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(&path)
+        .unwrap();
+    let mut used = [0u8; 8];
+    file.seek(SeekFrom::Start(8)).unwrap();
+    file.read_exact(&mut used).unwrap();
+    let used_before = u64::from_ne_bytes(used);
+    file.seek(SeekFrom::Start(used_before)).unwrap();
+    file.write_all(&((1u32 << 31) | 9).to_ne_bytes()).unwrap();
+    file.seek(SeekFrom::Start(8)).unwrap();
+    file.write_all(&(used_before + 4 + 8).to_ne_bytes())
+        .unwrap();
+    file.flush().unwrap();
+
+    interner.intern("after").unwrap();
+    let other = unsafe { MmapInterner::open(&path, 4096) }.unwrap();
+    assert_eq!(other.lookup("before"), Some("before"));
+    assert_eq!(other.lookup("after"), Some("after"));
+    let used = other.bytes_used();
+    other.intern("after").unwrap();
+    assert_eq!(other.bytes_used(), used);
+    assert_eq!(other.lookup("finished"), None);
+
+    // once the stalled writer does publish its string, it is picked up after all
+    file.seek(SeekFrom::Start(used_before + 4)).unwrap();
+    file.write_all(b"finished").unwrap();
+    file.seek(SeekFrom::Start(used_before)).unwrap();
+    file.write_all(&9u32.to_ne_bytes()).unwrap();
+    file.flush().unwrap();
+    assert_eq!(other.lookup("finished"), Some("finished"));
+    drop((interner, other));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn test_interned_bytes_deflate_inflate() {
+    let payload = b"interned interned interned interned payload".repeat(8);
+    let original: Interned<&[u8]> = payload.as_slice().into();
+    let compressed = original.deflate();
+    assert!(compressed.len() < original.len());
+    assert_eq!(original.deflate().as_ptr(), compressed.as_ptr());
+    let same_payload: Interned<&[u8]> = payload.clone().as_slice().into();
+    assert_eq!(same_payload.deflate().as_ptr(), compressed.as_ptr());
+    assert_eq!(compressed.inflate().unwrap().as_ptr(), original.as_ptr());
+    let garbage: Interned<&[u8]> = [0xffu8, 0xff, 0xff].as_slice().into();
+    assert!(garbage.inflate().is_err());
+}
+
+#[cfg(feature = "unicode-segmentation")]
+#[test]
+fn test_interned_graphemes() {
+    // "e" followed by U+0301 COMBINING ACUTE ACCENT forms a single grapheme cluster
+    let a: Interned<&str> = "ne\u{301}e\u{301}!".into();
+    let graphemes = a.graphemes();
+    assert_eq!(graphemes.len(), 4);
+    assert_eq!(graphemes[0].interned_str(), "n");
+    assert_eq!(graphemes[1].interned_str(), "e\u{301}");
+    assert_eq!(graphemes[1].as_ptr(), graphemes[2].as_ptr());
+    let b: Interned<&str> = "e\u{301}".into();
+    assert_eq!(b.graphemes(), vec![b]);
+    assert_eq!(graphemes[1].as_ptr(), b.as_ptr());
+}
+
+#[test]
+fn test_keywords() {
+    keywords! {
+        enum Keyword {
+            Let = "let",
+            If = "if",
+            Else = "else",
+        }
+    }
+
+    assert_eq!(Keyword::Let.intern(), Interned::from("let"));
+    assert_eq!(
+        Keyword::Let.intern().as_ptr(),
+        Interned::from("let").as_ptr()
+    );
+    assert_eq!(Keyword::If.intern(), Keyword::If.intern());
+    assert_ne!(Keyword::If.intern(), Keyword::Else.intern());
+    assert_eq!(Keyword::Else.as_str(), "else");
+    assert_eq!(Keyword::from_str("if"), Some(Keyword::If));
+    assert_eq!(Keyword::from_str("If"), None);
+    assert_eq!(Keyword::ALL, &[Keyword::Let, Keyword::If, Keyword::Else]);
+    assert_eq!(Keyword::Else.to_string(), "else");
+    let interned: Interned<&str> = Keyword::If.into();
+    assert_eq!(interned.interned_str(), "if");
+}
+
+#[test]
+fn test_interned_slice_dedup_consecutive() {
+    let a = Interned::from([1, 1, 2, 2, 3].as_slice());
+    let b = Interned::from([1, 2, 3].as_slice());
+    let deduped = a.dedup_consecutive();
+    assert_eq!(deduped.interned_slice(), &[1, 2, 3]);
+    assert_eq!(deduped, b);
+    assert_eq!(deduped.as_ptr(), b.as_ptr());
+    assert_eq!(b.dedup_consecutive().as_ptr(), b.as_ptr());
+    let c = Interned::from([4, 4, 5, 4].as_slice());
+    assert_eq!(c.dedup_consecutive().interned_slice(), &[4, 5, 4]);
+}
+
+#[test]
+fn test_memoized_from_once() {
+    use std::cell::Cell;
+
+    let calls = Cell::new(0);
+    let owned = String::from("moved into the generator");
+    let a: Memoized<usize, &str> = Memoized::from_once("from_once scope", 1, |_| {
+        calls.set(calls.get() + 1);
+        let consumed: String = owned;
+        consumed.as_str().into()
+    });
+    assert_eq!(a.as_str(), "moved into the generator");
+    assert_eq!(calls.get(), 1);
+
+    let owned = String::from("never used");
+    let b: Memoized<usize, &str> = Memoized::from_once("from_once scope", 1, |_| {
+        calls.set(calls.get() + 1);
+        let consumed: String = owned;
+        consumed.as_str().into()
+    });
+    assert_eq!(a, b);
+    assert_eq!(b.as_str(), "moved into the generator");
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn test_interned_str_truncate() {
+    let a: Interned<&str> = "hello".into();
+    let truncated = a.truncate_interned(3);
+    assert_eq!(truncated.interned_str(), "hel");
+    assert_eq!(truncated.as_ptr(), Interned::from("hel").as_ptr());
+    assert_eq!(a.truncate_interned(5).as_ptr(), a.as_ptr());
+    assert_eq!(a.truncate_interned(50).as_ptr(), a.as_ptr());
+    assert_eq!(a.truncate_interned(0).interned_str(), "");
+    let b: Interned<&str> = "héllo wörld".into();
+    assert_eq!(b.truncate_interned(2).interned_str(), "hé");
+    assert_eq!(b.truncate_interned(8).interned_str(), "héllo wö");
+}
+
+#[test]
+fn test_in_str_bytes() {
+    let a = InStr::from("héllo");
+    assert_eq!(a.byte_len(), 6);
+    assert_eq!(a.chars().count(), 5);
+    assert_eq!(a.as_bytes(), "héllo".as_bytes());
+    assert_eq!(a.as_bytes(), &[104, 195, 169, 108, 108, 111]);
+    assert_eq!(a.as_bytes().as_ptr(), a.as_str().as_ptr());
+    assert!(!a.is_empty());
+    assert!(InStr::from("").is_empty());
+    let bytes: &'static [u8] = InStr::from(String::from("static")).as_bytes();
+    assert_eq!(bytes, b"static");
+}
+
+#[cfg(feature = "rkyv")]
+#[test]
+fn test_rkyv_round_trip() {
+    use rkyv::{rancor::Error, Archive, Deserialize, Serialize};
+
+    #[derive(Archive, Serialize, Deserialize)]
+    struct SymbolTable {
+        symbols: Vec<InStr>,
+        blob: Interned<&'static [u8]>,
+    }
+
+    let table = SymbolTable {
+        symbols: vec!["alpha".into(), "beta".into(), "alpha".into()],
+        blob: vec![1u8, 2, 3, 4].into(),
+    };
+    let bytes = rkyv::to_bytes::<Error>(&table).unwrap();
+
+    let archived = rkyv::access::<ArchivedSymbolTable, Error>(&bytes).unwrap();
+    assert_eq!(archived.symbols.len(), 3);
+    assert_eq!(archived.symbols[1].as_str(), "beta");
+    assert_eq!(archived.blob.as_slice(), &[1, 2, 3, 4]);
+
+    let restored = rkyv::from_bytes::<SymbolTable, Error>(&bytes).unwrap();
+    assert_eq!(restored.symbols, table.symbols);
+    assert_eq!(restored.symbols[0].as_ptr(), table.symbols[0].as_ptr());
+    assert_eq!(restored.symbols[0].as_ptr(), restored.symbols[2].as_ptr());
+    assert_eq!(restored.blob, table.blob);
+    assert_eq!(restored.blob.as_ptr(), table.blob.as_ptr());
+}
+
+#[test]
+fn test_interned_str_split_once() {
+    let a: Interned<&str> = "a=b".into();
+    let (key, value) = a.split_once_interned('=').unwrap();
+    assert_eq!(key.interned_str(), "a");
+    assert_eq!(value.interned_str(), "b");
+    assert_eq!(key.as_ptr(), Interned::from("a").as_ptr());
+    assert_eq!(value.as_ptr(), Interned::from("b").as_ptr());
+    let b: Interned<&str> = "no-delim".into();
+    assert!(b.split_once_interned('=').is_none());
+    let c: Interned<&str> = "k=v=w".into();
+    let (key, value) = c.split_once_interned('=').unwrap();
+    assert_eq!((key.interned_str(), value.interned_str()), ("k", "v=w"));
+}
+
+/// Global allocator wrapper that counts allocations made by the current thread, so tests can
+/// assert that a code path is allocation-free regardless of what other tests are doing.
+struct CountingAllocator;
+
+thread_local! {
+    static THREAD_ALLOCATIONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+unsafe impl std::alloc::GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+        let _ = THREAD_ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { std::alloc::System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+        unsafe { std::alloc::System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn thread_allocations() -> usize {
+    THREAD_ALLOCATIONS.with(|count| count.get())
+}
+
+#[test]
+fn test_interned_u8_cache() {
+    let first: Interned<u8> = Interned::from(7u8);
+    assert_eq!(num_interned::<u8>(), 1);
+    let _ = Interned::from(7u8);
+    assert_eq!(num_interned::<u8>(), 1);
+    let all: Vec<Interned<u8>> = (0..=255u8).map(Interned::from).collect();
+    assert_eq!(num_interned::<u8>(), 256);
+    assert_eq!(all[7].as_ptr(), first.as_ptr());
+    for (i, interned) in all.iter().enumerate() {
+        assert_eq!(*interned.interned_value() as usize, i);
+    }
+
+    let before = thread_allocations();
+    for _ in 0..4 {
+        for byte in 0..=255u8 {
+            let interned = Interned::from(byte);
+            assert_eq!(interned.as_ptr(), all[byte as usize].as_ptr());
+        }
+    }
+    assert_eq!(thread_allocations(), before);
+    assert_eq!(num_interned::<u8>(), 256);
+
+    let memoized: Memoized<u8, u8> = Memoized::from("u8 cache", 3, |x| (x * 2).into());
+    assert_eq!(memoized.interned().as_ptr(), all[6].as_ptr());
+}
+
+#[test]
+fn test_interned_str_replace() {
+    let a: Interned<&str> = "aaa".into();
+    let replaced = a.replace_interned("a", "b");
+    assert_eq!(replaced.interned_str(), "bbb");
+    assert_eq!(replaced.as_ptr(), Interned::from("bbb").as_ptr());
+    assert_eq!(a.replace_interned("z", "b").as_ptr(), a.as_ptr());
+    assert_eq!(a.replace_interned("a", "a").as_ptr(), a.as_ptr());
+    let b: Interned<&str> = "hello world".into();
+    assert_eq!(b.replace_interned("o", "0").interned_str(), "hell0 w0rld");
+}
+
+#[test]
+fn test_memoized_recursive() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static CALLS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn fib(n: u64) -> Memoized<u64, u64> {
+        Memoized::from("recursive fib", n, |n| {
+            CALLS.with(|calls| calls.set(calls.get() + 1));
+            match n {
+                0 | 1 => n.into(),
+                _ => (*fib(n - 1).as_value() + *fib(n - 2).as_value()).into(),
+            }
+        })
+    }
+
+    assert_eq!(*fib(50).as_value(), 12586269025);
+    assert_eq!(CALLS.with(|calls| calls.get()), 51);
+    assert_eq!(*fib(40).as_value(), 102334155);
+    assert_eq!(CALLS.with(|calls| calls.get()), 51);
+}
+
+#[test]
+fn test_interned_slice_reversed_and_rotated() {
+    let a = Interned::from([1, 2, 3].as_slice());
+    let reversed = a.reversed();
+    assert_eq!(reversed.interned_slice(), &[3, 2, 1]);
+    assert_eq!(
+        reversed.as_ptr(),
+        Interned::from([3, 2, 1].as_slice()).as_ptr()
+    );
+    assert_eq!(reversed.reversed().as_ptr(), a.as_ptr());
+    assert_eq!(a.rotated(1).interned_slice(), &[2, 3, 1]);
+    assert_eq!(a.rotated(2).interned_slice(), &[3, 1, 2]);
+    assert_eq!(a.rotated(3).as_ptr(), a.as_ptr());
+    assert_eq!(a.rotated(4).as_ptr(), a.rotated(1).as_ptr());
+    let empty = Interned::from([0u64; 0].as_slice());
+    assert_eq!(empty.rotated(5).as_ptr(), empty.as_ptr());
+}
+
+#[test]
+fn test_interned_str_to_utf16() {
+    let a: Interned<&str> = "abc".into();
+    let utf16 = a.to_utf16();
+    assert_eq!(utf16.interned_slice(), &[97, 98, 99]);
+    assert_eq!(
+        utf16.interned_slice(),
+        "abc".encode_utf16().collect::<Vec<_>>().as_slice()
+    );
+    assert_eq!(utf16.as_ptr(), a.to_utf16().as_ptr());
+    let b: Interned<&str> = "h\u{e9}\u{1f600}".into();
+    assert_eq!(
+        b.to_utf16().interned_slice(),
+        "h\u{e9}\u{1f600}"
+            .encode_utf16()
+            .collect::<Vec<_>>()
+            .as_slice()
+    );
+    assert_eq!(b.to_utf16().len(), 4);
+}
+
+#[test]
+fn test_memoized_value_cmp() {
+    use std::cmp::Ordering;
+
+    let a: Memoized<usize, &str> = Memoized::from("value_cmp", 1, |_| "apple".into());
+    let b: Memoized<(bool, char), &str> =
+        Memoized::from("value_cmp", (true, 'x'), |_| "banana".into());
+    let c: Memoized<&str, &str> = Memoized::from("value_cmp", "key", |_| "apple".into());
+    assert_eq!(a.value_cmp(&b), Ordering::Less);
+    assert_eq!(b.value_cmp(&a), Ordering::Greater);
+    assert_eq!(a.value_cmp(&c), Ordering::Equal);
+    assert_eq!(c.value_cmp(&b), Ordering::Less);
+}
+
+#[test]
+fn test_interned_slice_debug_limit() {
+    let short: Interned<&[u8]> = vec![1u8, 2, 3].into();
+    assert_eq!(format!("{short:?}"), "Interned<&[u8]> { slice: [1, 2, 3] }");
+    assert!(format!("{short:#?}").contains("1,\n"));
+    let at_limit: Interned<&[u8]> = vec![0u8; DEBUG_SLICE_LIMIT].into();
+    assert!(format!("{at_limit:#?}").contains("0,\n"));
+    let long: Interned<&[u8]> = vec![7u8; 1000].into();
+    // plain `{:?}` is unchanged, only `{:#?}` elides
+    assert!(format!("{long:?}").starts_with("Interned<&[u8]> { slice: [7, 7, 7"));
+    assert!(format!("{long:#?}").contains("slice: [..1000 bytes..],\n    hash: "));
+    assert!(!format!("{long:#?}").contains("7,"));
+    let wide: Interned<&[u32]> = Interned::from(vec![7u32; 1000].as_slice());
+    assert!(format!("{wide:#?}").contains("[..4000 bytes..]"));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_interned_chrono() {
+    use interned::datetime::{DateTimeUtc, NaiveDate, NaiveDateTime};
+
+    let date = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let a: Interned<NaiveDate> = date(2023, 5, 17).into();
+    let b: Interned<NaiveDate> = Interned::from(NaiveDate(date(2023, 5, 17)));
+    let c: Interned<NaiveDate> = date(2021, 1, 1).into();
+    assert_eq!(a, b);
+    assert_eq!(a.as_ptr(), b.as_ptr());
+    assert_ne!(a, c);
+    assert!(c < a);
+    assert_eq!(a.succ_opt(), Some(date(2023, 5, 18)));
+
+    let naive_time = date(2023, 5, 17).and_hms_opt(12, 30, 0).unwrap();
+    let d: Interned<NaiveDateTime> = naive_time.into();
+    let e: Interned<NaiveDateTime> = naive_time.into();
+    assert_eq!(d.as_ptr(), e.as_ptr());
+    assert_eq!(d.to_string(), "2023-05-17 12:30:00");
+
+    let utc = naive_time.and_utc();
+    let f: Interned<DateTimeUtc> = utc.into();
+    let g: Interned<DateTimeUtc> = (utc + chrono::Duration::seconds(1)).into();
+    assert_eq!(f.as_ptr(), Interned::<DateTimeUtc>::from(utc).as_ptr());
+    assert!(f < g);
+    assert_eq!(f.interned_value().0, utc);
+}
+
+#[test]
+fn test_interned_str_to_os_str() {
+    use std::ffi::OsStr;
+
+    let a: Interned<&str> = "some/os/string".into();
+    let os_str = a.to_os_str();
+    assert_eq!(os_str.interned_os_str(), OsStr::new("some/os/string"));
+    assert_eq!(os_str, Interned::from(OsStr::new("some/os/string")));
+    assert_eq!(os_str.as_ptr(), a.to_os_str().as_ptr());
+    let b = InStr::from("some/os/string");
+    assert_eq!(
+        b.to_in_os_str(),
+        InOsStr::from(OsStr::new("some/os/string"))
+    );
+    assert_eq!(
+        b.to_in_os_str().as_ptr(),
+        InOsStr::from(OsStr::new("some/os/string")).as_ptr()
+    );
+}
+
+#[test]
+fn test_interned_dyn() {
+    let a = Interned::<i32>::from(-42);
+    let b = Interned::<&str>::from("hello dyn");
+    let values: Vec<Box<dyn InternedDyn>> = vec![Box::new(a), Box::new(b)];
+    for value in &values {
+        println!(
+            "{} ({:x}): {:?}",
+            value.type_name(),
+            value.hash_code(),
+            value
+        );
+    }
+    assert_eq!(values[0].as_ptr(), a.as_ptr());
+    assert_eq!(values[1].as_ptr(), b.as_ptr());
+    assert_eq!(values[0].type_name(), "i32");
+    assert_eq!(values[1].type_name(), "&str");
+    assert_eq!(format!("{:?}", values[0]), "-42");
+    assert_eq!(format!("{:?}", values[1]), "\"hello dyn\"");
+    assert_ne!(values[0].hash_code(), values[1].hash_code());
+}
+
+#[test]
+fn test_interned_slice_binary_search() {
+    let a: Interned<&[i32]> = [1, 3, 5, 7, 9, 11].as_slice().into();
+    assert_eq!(a.binary_search(&1), Ok(0));
+    assert_eq!(a.binary_search(&7), Ok(3));
+    assert_eq!(a.binary_search(&11), Ok(5));
+    assert_eq!(a.binary_search(&0), Err(0));
+    assert_eq!(a.binary_search(&6), Err(3));
+    assert_eq!(a.binary_search(&12), Err(6));
+    let empty: Interned<&[i32]> = [0i32; 0].as_slice().into();
+    assert_eq!(empty.binary_search(&1), Err(0));
+}
+
+#[test]
+fn test_byte_view() {
+    let a: Interned<&[u8]> = b"abca".as_slice().into();
+    let initial = num_interned::<&[u8]>();
+    let bytes: Vec<Interned<&[u8]>> = a.byte_view().collect();
+    // only the distinct bytes seen are interned
+    assert_eq!(num_interned::<&[u8]>(), initial + 3);
+    assert_eq!(bytes.len(), 4);
+    assert_eq!(bytes[0].interned_slice(), b"a");
+    assert_eq!(bytes[1].interned_slice(), b"b");
+    assert_eq!(bytes[0].as_ptr(), bytes[3].as_ptr());
+    assert_ne!(bytes[0].as_ptr(), bytes[1].as_ptr());
+    assert_eq!(bytes[0], Interned::<&[u8]>::from(b"a".as_slice()));
+    assert_eq!(
+        bytes[0].as_ptr(),
+        Interned::<&[u8]>::from(b"a".as_slice()).as_ptr()
+    );
+    let s: Interned<&str> = "cab".into();
+    let from_str: Vec<Interned<&[u8]>> = s.bytes_interned().collect();
+    assert_eq!(from_str[1].as_ptr(), bytes[0].as_ptr());
+    assert_eq!(from_str[0].as_ptr(), a.byte_view().nth(2).unwrap().as_ptr());
+}
+
+#[test]
+fn test_eq_str_and_eq_bytes_do_not_intern() {
+    let a: Interned<&str> = "needle".into();
+    let b: Interned<&[u8]> = b"needle".as_slice().into();
+    let initial_str = num_interned::<&str>();
+    let initial_bytes = num_interned::<&[u8]>();
+    for probe in ["haystack", "needle", "needles", "", "needlf"] {
+        let probe = probe.to_string();
+        assert_eq!(a.eq_str(&probe), probe == "needle");
+        assert_eq!(b.eq_bytes(probe.as_bytes()), probe == "needle");
+    }
+    assert_eq!(num_interned::<&str>(), initial_str);
+    assert_eq!(num_interned::<&[u8]>(), initial_bytes);
+}
+
+#[test]
+fn test_count_interned_where() {
+    let long_before = count_interned_str_where(|s| s.len() > 3);
+    let short_before = count_interned_str_where(|s| s.len() <= 3);
+    for s in ["ab", "abc", "abcd", "abcde", "abcd", "xyzzy", "ab"] {
+        let _ = Interned::<&str>::from(s);
+    }
+    assert_eq!(count_interned_str_where(|s| s.len() > 3), long_before + 3);
+    assert_eq!(count_interned_str_where(|s| s.len() <= 3), short_before + 2);
+    assert_eq!(count_interned_str_where(|_| true), num_interned::<&str>());
+
+    let before = count_interned_where::<i64>(|v| *v < 0);
+    for v in [-1i64, -2, -1, 5, 6] {
+        let _ = Interned::<i64>::from(v);
+    }
+    assert_eq!(count_interned_where::<i64>(|v| *v < 0), before + 2);
+    assert_eq!(count_interned_where::<u128>(|_| true), 0);
+}
+
+#[test]
+fn test_interned_path_components() {
+    use std::{ffi::OsStr, path::Path};
+
+    let a: Interned<&Path> = Path::new("/usr/local/bin").into();
+    let b: Interned<&Path> = Path::new("/usr/share/doc").into();
+    let a_components = a.interned_components();
+    let b_components = b.interned_components();
+    assert_eq!(a_components.len(), 4);
+    assert_eq!(b_components.len(), 4);
+    assert_eq!(a_components[1].interned_os_str(), OsStr::new("usr"));
+    assert_eq!(a_components[0], b_components[0]);
+    assert_eq!(a_components[1], b_components[1]);
+    assert_eq!(a_components[1].as_ptr(), b_components[1].as_ptr());
+    assert_ne!(a_components[2], b_components[2]);
+    assert_eq!(a_components[3].interned_os_str(), OsStr::new("bin"));
+}
+
+#[test]
+fn test_interned_slice_to_array() {
+    let a: Interned<&[u8]> = [0xdeu8, 0xad, 0xbe, 0xef].as_slice().into();
+    let array: [u8; 4] = a.to_array().unwrap();
+    assert_eq!(array, [0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(u32::from_be_bytes(array), 0xdeadbeef);
+    assert_eq!(a.to_array::<3>(), None);
+    assert_eq!(a.to_array::<5>(), None);
+    let empty: Interned<&[u8]> = [0u8; 0].as_slice().into();
+    assert_eq!(empty.to_array::<0>(), Some([]));
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_interned_json() {
+    use interned::json::InJson;
+    use serde_json::json;
+
+    let a = InJson::from(json!({"b": 1, "a": {"y": [1, 2, {"q": null, "p": true}], "x": "s"}}));
+    let b = InJson::from(
+        serde_json::from_str::<serde_json::Value>(
+            r#"{ "a": { "x": "s", "y": [1, 2, { "p": true, "q": null }] }, "b": 1 }"#,
+        )
+        .unwrap(),
+    );
+    assert_eq!(a, b);
+    assert_eq!(a.as_ptr(), b.as_ptr());
+    assert_eq!(
+        a.as_str(),
+        r#"{"a":{"x":"s","y":[1,2,{"p":true,"q":null}]},"b":1}"#
+    );
+    assert_eq!(
+        a.to_value(),
+        json!({"a": {"x": "s", "y": [1, 2, {"p": true, "q": null}]}, "b": 1})
+    );
+    let c = InJson::from(json!({"b": 2, "a": {}}));
+    assert_ne!(a, c);
+    // arrays are ordered, so element order is significant
+    assert_ne!(InJson::from(json!([1, 2])), InJson::from(json!([2, 1])));
+    assert_eq!(InJson::from(json!("a\"b")).as_str(), r#""a\"b""#);
+}
+
+#[test]
+fn test_wrapper_eq_raw_handle() {
+    use std::{ffi::OsStr, path::Path};
+
+    let raw_str: Interned<&str> = "wrapped".into();
+    assert_eq!(InStr::from("wrapped"), raw_str);
+    assert_eq!(raw_str, InStr::from("wrapped"));
+    assert_ne!(InStr::from("unwrapped"), raw_str);
+    assert_ne!(raw_str, InStr::from("unwrapped"));
+
+    let raw_os_str: Interned<&OsStr> = OsStr::new("wrapped").into();
+    assert_eq!(InOsStr::from(OsStr::new("wrapped")), raw_os_str);
+    assert_eq!(raw_os_str, InOsStr::from(OsStr::new("wrapped")));
+    assert_ne!(InOsStr::from(OsStr::new("other")), raw_os_str);
+    assert_ne!(raw_os_str, InOsStr::from(OsStr::new("other")));
+
+    let raw_path: Interned<&Path> = Path::new("/a/b").into();
+    assert_eq!(InPath::from(Path::new("/a/b")), raw_path);
+    assert_eq!(raw_path, InPath::from(Path::new("/a/b")));
+    assert_ne!(InPath::from(Path::new("/a/c")), raw_path);
+    assert_ne!(raw_path, InPath::from(Path::new("/a/c")));
+}
+
+#[test]
+fn test_shared_interned_from_local() {
+    use std::thread;
+
+    let local: Interned<&str> = "promote me".into();
+    let promoted = SharedInterned::from_local(local);
+    assert_eq!(promoted.as_str(), "promote me");
+    assert_ne!(promoted.as_ptr(), local.as_ptr());
+    assert_eq!(promoted, SharedInterned::from("promote me"));
+
+    let handles: Vec<_> = (0..2)
+        .map(|_| {
+            thread::spawn(|| {
+                let local: Interned<&str> = "promote me".into();
+                SharedInterned::from_local(local)
+            })
+        })
+        .collect();
+    for handle in handles {
+        let shared = handle.join().unwrap();
+        assert_eq!(shared, promoted);
+        assert_eq!(shared.as_ptr(), promoted.as_ptr());
+    }
+}
+
+#[test]
+fn test_shared_interned_slices() {
+    let local: Interned<&[u32]> = [1u32, 2, 3].as_slice().into();
+    let promoted = SharedInterned::from_local_slice(local);
+    assert_eq!(promoted.as_slice(), [1, 2, 3]);
+    assert_eq!(promoted, SharedInterned::from([1u32, 2, 3].as_slice()));
+    assert_ne!(promoted, SharedInterned::from([3u32, 2, 1].as_slice()));
+    let ptr = promoted.as_ptr() as usize;
+    let other =
+        std::thread::spawn(|| SharedInterned::from([1u32, 2, 3].as_slice()).as_ptr() as usize);
+    assert_eq!(other.join().unwrap(), ptr);
+    // pools for different element types are kept apart
+    let bytes = SharedInterned::from([1u8, 2, 3].as_slice());
+    assert_eq!(bytes.as_slice(), [1, 2, 3]);
+    assert_eq!(SharedInterned::from([1u8, 2, 3].as_slice()), bytes);
+    // zero-sized slices of different lengths share a pointer but are not equal
+    let units = SharedInterned::from([(); 3].as_slice());
+    let fewer = SharedInterned::from([(); 1].as_slice());
+    assert_ne!(units, fewer);
+    assert_eq!(units, SharedInterned::from([(); 3].as_slice()));
+}
+
+#[test]
+fn test_interned_slice_contains_position() {
+    let a: Interned<&[i32]> = [4, 8, 15, 16, 23, 42, 8].as_slice().into();
+    assert!(a.contains(&15));
+    assert!(a.contains(&42));
+    assert!(!a.contains(&7));
+    assert_eq!(a.position(&4), Some(0));
+    assert_eq!(a.position(&8), Some(1));
+    assert_eq!(a.position(&42), Some(5));
+    assert_eq!(a.position(&7), None);
+}
+
+#[test]
+fn test_rate_limited_interner() {
+    use std::time::Duration;
+
+    let window = Duration::from_millis(500);
+    let mut interner = RateLimitedInterner::new(5, window);
+    let initial = num_interned::<&str>();
+    let mut results = Vec::new();
+    for i in 0..8 {
+        results.push(interner.intern(format!("rate limited {i}").as_str()));
+    }
+    assert!(results[..5].iter().all(|result| result.is_ok()));
+    assert!(results[5..]
+        .iter()
+        .all(|result| *result == Err(RateLimited)));
+    assert_eq!(num_interned::<&str>(), initial + 5);
+    // hits on already-interned values are always allowed
+    assert_eq!(
+        interner.intern("rate limited 0").unwrap(),
+        Interned::from("rate limited 0")
+    );
+    assert_eq!(interner.remaining(), 0);
+
+    std::thread::sleep(window);
+    assert_eq!(interner.remaining(), 5);
+    assert!(interner.intern("rate limited 5").is_ok());
+    assert_eq!(num_interned::<&str>(), initial + 6);
+}
+
+#[cfg(feature = "heck")]
+#[test]
+fn test_interned_str_case_transforms() {
+    use heck::{ToLowerCamelCase, ToSnakeCase, ToTitleCase};
+
+    let a: Interned<&str> = "hello_world_again".into();
+    let b: Interned<&str> = "HelloWorldAgain".into();
+    let c: Interned<&str> = "hello world again".into();
+    assert_eq!(
+        a.to_title_case().interned_str(),
+        "Hello World Again".to_title_case().as_str()
+    );
+    assert_eq!(
+        a.to_title_case().interned_str(),
+        "hello_world_again".to_title_case().as_str()
+    );
+    assert_eq!(
+        b.to_snake_case().interned_str(),
+        "HelloWorldAgain".to_snake_case().as_str()
+    );
+    assert_eq!(
+        c.to_camel_case().interned_str(),
+        "hello world again".to_lower_camel_case().as_str()
+    );
+    assert_eq!(a.to_title_case().as_ptr(), b.to_title_case().as_ptr());
+    assert_eq!(a.to_title_case().as_ptr(), c.to_title_case().as_ptr());
+    assert_eq!(b.to_snake_case().as_ptr(), a.as_ptr());
+    assert_eq!(a.to_camel_case().as_ptr(), c.to_camel_case().as_ptr());
+    assert_eq!(a.to_camel_case().interned_str(), "helloWorldAgain");
+}
+
+#[test]
+fn test_interned_slice_split_at() {
+    let a: Interned<&[i32]> = [1, 2, 3, 4].as_slice().into();
+    let (left, right) = a.split_at_interned(2).unwrap();
+    assert_eq!(left, Interned::from([1, 2].as_slice()));
+    assert_eq!(right, Interned::from([3, 4].as_slice()));
+    assert_eq!(left.as_ptr(), Interned::from([1, 2].as_slice()).as_ptr());
+    let (empty, all) = a.split_at_interned(0).unwrap();
+    assert!(empty.interned_slice().is_empty());
+    assert_eq!(all, a);
+    let (all, empty) = a.split_at_interned(4).unwrap();
+    assert_eq!(all, a);
+    assert!(empty.interned_slice().is_empty());
+    assert_eq!(a.split_at_interned(5), None);
+}
+
+#[test]
+fn test_interned_bool_and_unit_never_allocate() {
+    let first_true = Interned::from(true);
+    let first_false = Interned::from(false);
+    let first_unit = Interned::from(());
+    assert_ne!(first_true.as_ptr(), first_false.as_ptr());
+    assert_eq!(num_interned::<bool>(), 2);
+
+    let before = thread_allocations();
+    for i in 0..1000 {
+        assert_eq!(Interned::from(true).as_ptr(), first_true.as_ptr());
+        assert_eq!(Interned::from(i % 2 == 1).interned_value(), &(i % 2 == 1));
+        assert_eq!(Interned::from(()).as_ptr(), first_unit.as_ptr());
+    }
+    assert_eq!(thread_allocations(), before);
+    assert_eq!(num_interned::<bool>(), 2);
+    assert_eq!(num_interned::<()>(), 1);
+    assert!(*first_true.interned_value());
+    assert!(!*first_false.interned_value());
+}
+
+/// A value whose hash deliberately collides with that of every other [`Colliding`] value, used
+/// to exercise how the interner handles hash collisions between distinct values.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+struct Colliding(u32);
+
+impl Hash for Colliding {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        0u8.hash(state)
+    }
+}
+
+staticize::derive_staticize!(Colliding);
+unsafe_impl_data_type!(Colliding, Value);
+derive_from_interned_impl_value!(Colliding);
+
+/// Asserts that `==`, [`Hash`], and [`Ord`] agree with each other for every pair of `values`:
+/// `a == b` implies `hash(a) == hash(b)`, and `a.cmp(b) == Equal` if and only if `a == b`.
+fn assert_eq_hash_ord_consistent<T: Hash>(values: &[Interned<T>])
+where
+    Interned<T>: Hash + Ord + std::fmt::Debug,
+{
+    use std::{cmp::Ordering, hash::Hasher};
+
+    let hash_of = |value: &Interned<T>| {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    };
+    for a in values {
+        for b in values {
+            if a == b {
+                assert_eq!(hash_of(a), hash_of(b), "{a:?} == {b:?} but hashes differ");
+            }
+            assert_eq!(a.cmp(b) == Ordering::Equal, a == b, "{a:?} vs {b:?}");
+            assert_eq!(a.partial_cmp(b), Some(a.cmp(b)), "{a:?} vs {b:?}");
+        }
+    }
+}
+
+#[test]
+fn test_eq_hash_ord_consistency() {
+    use std::{ffi::OsStr, path::Path};
+
+    assert_eq_hash_ord_consistent(&[0, 1, -1, i32::MAX, i32::MIN, 1, 0].map(Interned::<i32>::from));
+    assert_eq_hash_ord_consistent(&[true, false, true].map(Interned::<bool>::from));
+    assert_eq_hash_ord_consistent(&['a', 'b', 'a', '€'].map(Interned::<char>::from));
+    assert_eq_hash_ord_consistent(
+        &[Colliding(1), Colliding(2), Colliding(3), Colliding(1)].map(Interned::from),
+    );
+    assert_eq_hash_ord_consistent(
+        &[[].as_slice(), &[1], &[1, 2], &[2], &[1, 2], &[1, 3], &[]].map(Interned::<&[i32]>::from),
+    );
+    assert_eq_hash_ord_consistent(
+        &[[(); 0].as_slice(), &[()], &[(), ()], &[()]].map(Interned::<&[()]>::from),
+    );
+    assert_eq_hash_ord_consistent(
+        &["", "a", "ab", "a"].map(|s| Interned::<&OsStr>::from(OsStr::new(s))),
+    );
+    assert_eq_hash_ord_consistent(
+        &["/", "/a", "/a/b", "a", "/a"].map(|s| Interned::<&Path>::from(Path::new(s))),
+    );
+}
+
+#[test]
+fn test_hash_collisions_intern_distinct_values() {
+    let a = Interned::from(Colliding(10));
+    let b = Interned::from(Colliding(20));
+    let c = Interned::from(Colliding(30));
+    assert_eq!(a.interned_value(), &Colliding(10));
+    assert_eq!(b.interned_value(), &Colliding(20));
+    assert_eq!(c.interned_value(), &Colliding(30));
+    assert_ne!(a, b);
+    assert_ne!(b, c);
+    assert_ne!(a.as_ptr(), b.as_ptr());
+    assert_eq!(Interned::from(Colliding(20)), b);
+    assert_eq!(Interned::from(Colliding(20)).as_ptr(), b.as_ptr());
+    assert_eq!(Interned::from(Colliding(10)).as_ptr(), a.as_ptr());
+    assert_eq!(num_interned::<Colliding>(), 3);
+
+    let mut limiter = RateLimitedInterner::new(0, std::time::Duration::from_secs(60));
+    assert_eq!(limiter.intern(Colliding(30)), Ok(c));
+    assert_eq!(limiter.intern(Colliding(40)), Err(RateLimited));
+}
+
+#[test]
+fn test_interned_str_padding() {
+    let seven: Interned<&str> = "7".into();
+    assert_eq!(
+        seven.pad_start(3, '0').as_ptr(),
+        Interned::from("007").as_ptr()
+    );
+    assert_eq!(seven.pad_end(3, '.').interned_str(), "7..");
+    assert_eq!(seven.pad_start(1, '0').as_ptr(), seven.as_ptr());
+    assert_eq!(seven.pad_end(0, '0').as_ptr(), seven.as_ptr());
+    let wide: Interned<&str> = "é".into();
+    assert_eq!(wide.pad_start(3, '→').interned_str(), "→→é");
+    let already: Interned<&str> = "007".into();
+    assert_eq!(
+        already.pad_start(3, '0').as_ptr(),
+        seven.pad_start(3, '0').as_ptr()
+    );
+}
+
+#[test]
+fn test_interned_str_normalize_newlines() {
+    let windows: Interned<&str> = "a\r\nb".into();
+    let unix: Interned<&str> = "a\nb".into();
+    assert_eq!(windows.normalize_newlines().as_ptr(), unix.as_ptr());
+    assert_eq!(unix.normalize_newlines().as_ptr(), unix.as_ptr());
+    let mac: Interned<&str> = "a\rb\r\r\n".into();
+    assert_eq!(mac.normalize_newlines().interned_str(), "a\nb\n\n");
+}
+
+#[test]
+fn test_interned_str_reversed() {
+    let abc: Interned<&str> = "abc".into();
+    let cba = abc.reversed();
+    assert_eq!(cba.as_ptr(), Interned::from("cba").as_ptr());
+    assert_eq!(cba.reversed().as_ptr(), abc.as_ptr());
+    let start = thread_allocations();
+    assert_eq!(abc.reversed().reversed().as_ptr(), abc.as_ptr());
+    assert_eq!(thread_allocations(), start);
+    let multibyte: Interned<&str> = "añb→".into();
+    assert_eq!(multibyte.reversed().interned_str(), "→bña");
+    let palindrome: Interned<&str> = "racecar".into();
+    assert_eq!(palindrome.reversed().as_ptr(), palindrome.as_ptr());
+}
+
+#[test]
+fn test_by_ptr_map_keys() {
+    use std::collections::HashMap;
+
+    let mut map: HashMap<ByPtr<&str>, usize> = HashMap::new();
+    for (i, word) in ["alpha", "beta", "gamma", "beta"].into_iter().enumerate() {
+        *map.entry(Interned::from(word).into()).or_default() += i;
+    }
+    assert_eq!(map.len(), 3);
+    assert_eq!(map[&ByPtr(Interned::from("alpha"))], 0);
+    assert_eq!(map[&ByPtr(Interned::from("beta"))], 1 + 3);
+    assert_eq!(map[&ByPtr(Interned::from("gamma"))], 2);
+    assert_eq!(map.get(&ByPtr(Interned::from("delta"))), None);
+    let key = map
+        .keys()
+        .find(|key| key.interned_str() == "gamma")
+        .unwrap();
+    assert_eq!(key.into_inner(), Interned::from("gamma"));
+
+    let units: Vec<ByPtr<&[()]>> = [[(); 0].as_slice(), &[()], &[(), ()]]
+        .map(|units| ByPtr(Interned::from(units)))
+        .to_vec();
+    assert_ne!(units[0], units[1]);
+    assert_ne!(units[1], units[2]);
+}
+
+#[test]
+fn test_memoized_ptr_and_token() {
+    let a: Memoized<usize, &str> =
+        Memoized::from("token scope", 3, |n| "x".repeat(n).as_str().into());
+    let b: Memoized<usize, &str> =
+        Memoized::from("token scope", 3, |n| "x".repeat(n).as_str().into());
+    let c: Memoized<usize, &str> =
+        Memoized::from("token scope", 4, |n| "x".repeat(n).as_str().into());
+    assert_eq!(a.as_ptr(), b.as_ptr());
+    assert_eq!(a.token(), b.token());
+    assert_ne!(a.token(), c.token());
+    assert_eq!(a.token(), Interned::from("xxx").token());
+    assert_eq!(a.as_ptr(), a.interned().as_ptr());
+}
+
+#[test]
+fn test_interned_str_from_expanded() {
+    std::env::set_var("INTERNED_TEST_EXPAND_HOME", "/home/interned");
+    std::env::remove_var("INTERNED_TEST_EXPAND_UNSET");
+    let a = Interned::<&str>::from_expanded("$INTERNED_TEST_EXPAND_HOME/config");
+    let b = Interned::<&str>::from_expanded("${INTERNED_TEST_EXPAND_HOME}/config");
+    assert_eq!(a.interned_str(), "/home/interned/config");
+    assert_eq!(a.as_ptr(), b.as_ptr());
+    assert_eq!(a.as_ptr(), Interned::from("/home/interned/config").as_ptr());
+    assert_eq!(
+        Interned::<&str>::from_expanded("[$INTERNED_TEST_EXPAND_UNSET]").interned_str(),
+        "[]"
+    );
+    assert_eq!(
+        Interned::<&str>::from_expanded("cost: $5, ${unterminated, $ alone, $").interned_str(),
+        "cost: $5, ${unterminated, $ alone, $"
+    );
+    assert_eq!(
+        Interned::<&str>::from_expanded("x${INTERNED_TEST_EXPAND_HOME}y").interned_str(),
+        "x/home/internedy"
+    );
+}
+
+#[test]
+fn test_in_str_display_constructor() {
+    struct Point(i32, i32);
+
+    impl std::fmt::Display for Point {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "({}, {})", self.0, self.1)
+        }
+    }
+
+    assert_eq!(InStr::display(42), InStr::from("42"));
+    assert_eq!(InStr::display(42).as_ptr(), InStr::from("42").as_ptr());
+    assert_eq!(InStr::display(-1.5), "-1.5");
+    assert_eq!(InStr::display(Point(3, -4)), "(3, -4)");
+    assert_eq!(
+        InStr::display(Point(1, 2)).as_ptr(),
+        InStr::from("(1, 2)").as_ptr()
+    );
+}
+
+#[test]
+fn test_interned_str_slice_elements() {
+    let words = ["to", "be", "or", "not", "to", "be"];
+    let a: Interned<&[&str]> = words.as_slice().into();
+    assert_eq!(a.interned_slice(), &words);
+    let b: Interned<&[&str]> = [String::from("to"), String::from("be")]
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+        .into();
+    assert_eq!(b.interned_slice(), &["to", "be"]);
+    let elements = a.intern_elements();
+    assert_eq!(elements.len(), 6);
+    assert_eq!(elements[0].as_ptr(), elements[4].as_ptr());
+    assert_eq!(elements[1].as_ptr(), elements[5].as_ptr());
+    assert_ne!(elements[0].as_ptr(), elements[1].as_ptr());
+    assert_eq!(elements[3], Interned::from("not"));
+    assert_eq!(b.intern_elements()[0].as_ptr(), elements[0].as_ptr());
+    assert_eq!(
+        a.as_ptr(),
+        Interned::<&[&str]>::from(words.as_slice()).as_ptr()
+    );
+}
+
+#[cfg(feature = "unicode-normalization")]
+#[test]
+fn test_interned_str_unicode_normalization() {
+    let precomposed: Interned<&str> = "caf\u{e9}".into();
+    let decomposed: Interned<&str> = "cafe\u{301}".into();
+    assert_ne!(precomposed, decomposed);
+    assert_eq!(precomposed.to_nfc().as_ptr(), decomposed.to_nfc().as_ptr());
+    assert_eq!(precomposed.to_nfc().as_ptr(), precomposed.as_ptr());
+    assert_eq!(precomposed.to_nfd().as_ptr(), decomposed.to_nfd().as_ptr());
+    assert_eq!(precomposed.to_nfd().as_ptr(), decomposed.as_ptr());
+    assert_eq!(decomposed.to_nfc().interned_str().chars().count(), 4);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_interned_str_compiled_regex() {
+    let pattern: Interned<&str> = r"^\d+-\d+$".into();
+    let first = pattern.compiled_regex().unwrap();
+    let second = Interned::<&str>::from(r"^\d+-\d+$")
+        .compiled_regex()
+        .unwrap();
+    assert!(std::ptr::eq(first, second));
+    assert!(first.is_match("12-34"));
+    assert!(!first.is_match("12-ab"));
+    let other: Interned<&str> = r"^\w+$".into();
+    assert!(!std::ptr::eq(other.compiled_regex().unwrap(), first));
+    let invalid: Interned<&str> = "(unclosed".into();
+    assert!(invalid.compiled_regex().is_err());
+}
+
+#[cfg(feature = "sha2")]
+#[test]
+fn test_interned_slice_merkle_hash() {
+    let a: Interned<&[u32]> = Interned::from([1u32, 2, 3].as_slice());
+    let b: Interned<&[u32]> = Interned::from(vec![1u32, 2, 3].as_slice());
+    let changed: Interned<&[u32]> = Interned::from([1u32, 2, 4].as_slice());
+    assert_eq!(a.merkle_hash(), b.merkle_hash());
+    assert_ne!(a.merkle_hash(), changed.merkle_hash());
+    let reordered: Interned<&[u32]> = Interned::from([3u32, 2, 1].as_slice());
+    assert_ne!(a.merkle_hash(), reordered.merkle_hash());
+
+    let left: Interned<&[u32]> = Interned::from([1u32, 2].as_slice());
+    let right: Interned<&[u32]> = Interned::from([3u32].as_slice());
+    let parent = merkle::combine(&left.merkle_hash(), &right.merkle_hash());
+    assert_eq!(parent, a.merkle_hash());
+    assert_ne!(
+        parent,
+        merkle::combine(&right.merkle_hash(), &left.merkle_hash())
+    );
+    let empty: Interned<&[u32]> = Interned::from([0u32; 0].as_slice());
+    assert_ne!(empty.merkle_hash(), a.merkle_hash());
+
+    // digests are based on fixed little-endian encodings, not on `Hash` impls
+    use sha2::{Digest, Sha256};
+    let single: Interned<&[u32]> = Interned::from([1u32].as_slice());
+    let expected: [u8; 32] = Sha256::digest([0, 1, 0, 0, 0]).into();
+    assert_eq!(single.merkle_hash(), expected);
+    let words: Interned<&[&str]> = Interned::from(["ab", "c"].as_slice());
+    let leaf = |bytes: &[u8]| -> [u8; 32] { Sha256::digest([&[0], bytes].concat()).into() };
+    assert_eq!(
+        words.merkle_hash(),
+        merkle::combine(&leaf(b"ab"), &leaf(b"c"))
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_intern_many() {
+    let owned: Vec<String> = (0..10_000).map(|i| format!("par-{}", i % 1000)).collect();
+    let items: Vec<&str> = owned.iter().map(String::as_str).collect();
+    let handles = par_intern_many(&items);
+    assert_eq!(handles.len(), 10_000);
+    for (handle, item) in handles.iter().zip(&items) {
+        assert_eq!(handle.as_str(), *item);
+    }
+    let distinct: std::collections::HashSet<usize> = handles
+        .iter()
+        .map(|handle| handle.as_ptr() as usize)
+        .collect();
+    assert_eq!(distinct.len(), 1000);
+    assert_eq!(handles[7], handles[1007]);
+    assert_eq!(handles[7].as_ptr(), SharedInterned::from("par-7").as_ptr());
+    let from_thread = std::thread::spawn(|| SharedInterned::from("par-999").as_ptr() as usize);
+    assert_eq!(from_thread.join().unwrap(), handles[999].as_ptr() as usize);
+    let numbers: Vec<Vec<u64>> = (0..1000u64).map(|i| vec![i % 10, i % 3]).collect();
+    let slices: Vec<&[u64]> = numbers.iter().map(Vec::as_slice).collect();
+    let handles = par_intern_many(&slices);
+    for (handle, item) in handles.iter().zip(&slices) {
+        assert_eq!(handle.as_slice(), *item);
+    }
+    assert_eq!(handles[1], handles[31]);
+    assert_eq!(handles[1], SharedInterned::from([1u64, 1].as_slice()));
+}
+
+#[cfg(feature = "unicode-normalization")]
+#[test]
+fn test_interned_str_fold_diacritics() {
+    let plain: Interned<&str> = "cafe".into();
+    let precomposed: Interned<&str> = "caf\u{e9}".into();
+    let decomposed: Interned<&str> = "cafe\u{301}".into();
+    assert_eq!(precomposed.fold_diacritics().as_ptr(), plain.as_ptr());
+    assert_eq!(decomposed.fold_diacritics().as_ptr(), plain.as_ptr());
+    assert_eq!(plain.fold_diacritics().as_ptr(), plain.as_ptr());
+    let naive: Interned<&str> = "na\u{ef}ve \u{c5}ngstr\u{f6}m".into();
+    assert_eq!(naive.fold_diacritics(), Interned::from("naive Angstrom"));
+    let cjk: Interned<&str> = "\u{6f22}\u{5b57}".into();
+    assert_eq!(cjk.fold_diacritics().as_ptr(), cjk.as_ptr());
+}
+
+#[test]
+fn test_with_interned() {
+    for s in ["with", "interned", "iterates", "in", "place"] {
+        let _ = Interned::<&str>::from(s);
+    }
+    let total = num_interned::<&str>();
+    let before = thread_allocations();
+    let (count, long) = with_interned::<&str, _>(|values| {
+        assert_eq!(values.len(), total);
+        let mut count = 0;
+        let mut long = 0;
+        for value in values {
+            count += 1;
+            if value.interned_str().len() > 3 {
+                long += 1;
+            }
+        }
+        (count, long)
+    });
+    assert_eq!(thread_allocations(), before);
+    assert_eq!(count, total);
+    assert_eq!(long, count_interned_str_where(|s| s.len() > 3));
+    assert_eq!(with_interned::<u128, _>(|values| values.count()), 0);
+    let place = Interned::from("place");
+    assert!(with_interned::<&str, _>(
+        |mut values| values.any(|v| v == place)
+    ));
+}
+
+#[test]
+fn test_interned_into_owned() {
+    use std::{
+        ffi::{OsStr, OsString},
+        path::{Path, PathBuf},
+    };
+
+    let s: Interned<&str> = "owned str".into();
+    let owned: String = s.into();
+    assert_eq!(owned, "owned str");
+    assert_eq!(Interned::from(owned.as_str()), s);
+
+    let os: Interned<&OsStr> = OsStr::new("owned os").into();
+    let borrowed: &OsStr = os.into();
+    let owned: OsString = os.into();
+    assert_eq!(borrowed, OsStr::new("owned os"));
+    assert_eq!(owned, OsString::from("owned os"));
+    assert_eq!(Interned::from(owned.as_os_str()), os);
+
+    let path: Interned<&Path> = Path::new("/owned/path").into();
+    let borrowed: &Path = path.into();
+    let owned: PathBuf = path.into();
+    assert_eq!(borrowed, Path::new("/owned/path"));
+    assert_eq!(owned, PathBuf::from("/owned/path"));
+    assert_eq!(Interned::from(owned.as_path()).as_ptr(), path.as_ptr());
+}
+
+#[cfg(interned_no_intern)]
+#[test]
+fn test_no_intern_pass_through() {
+    let a = Interned::<i32>::from(1702);
+    let b = Interned::<i32>::from(1702);
+    assert_ne!(a.as_ptr(), b.as_ptr());
+    assert_eq!(a, b);
+    assert_ne!(a, Interned::from(1703));
+    let c: Interned<&str> = "pass-through".into();
+    let d: Interned<&str> = "pass-through".into();
+    assert_ne!(c.as_ptr(), d.as_ptr());
+    assert_eq!(c, d);
+    assert_eq!(InStr::from("pass-through"), d);
+    assert_eq!(d, InStr::from("pass-through"));
+    let e: Interned<&[u8]> = b"bytes".as_slice().into();
+    let f: Interned<&[u8]> = b"bytes".as_slice().into();
+    assert_ne!(e.as_ptr(), f.as_ptr());
+    assert_eq!(e, f);
+    assert_eq!(num_interned::<i32>(), 0);
+}
+
+#[test]
+fn test_in_str_from_fmt() {
+    let first = InStr::from_fmt(format_args!("x{}", 1));
+    assert_eq!(first, "x1");
+    assert_eq!(first.as_ptr(), InStr::from("x1").as_ptr());
+
+    let before = thread_allocations();
+    for _ in 0..100 {
+        let again = InStr::from_fmt(format_args!("x{}", 1));
+        assert_eq!(again.as_ptr(), first.as_ptr());
+    }
+    assert_eq!(thread_allocations(), before);
+
+    assert_eq!(InStr::from_fmt(format_args!("literal")), "literal");
+
+    struct Nested;
+
+    impl std::fmt::Display for Nested {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(&InStr::from_fmt(format_args!("inner-{}", 2)))
+        }
+    }
+
+    assert_eq!(
+        InStr::from_fmt(format_args!("outer-{}", Nested)),
+        "outer-inner-2"
+    );
+}
+
+#[test]
+fn test_interned_slice_ngrams() {
+    let a: Interned<&[i32]> = [1, 2, 1, 2].as_slice().into();
+    let bigrams = a.ngrams(2);
+    assert_eq!(bigrams.len(), 3);
+    assert_eq!(bigrams[0], Interned::from([1, 2].as_slice()));
+    assert_eq!(bigrams[1], Interned::from([2, 1].as_slice()));
+    assert_eq!(bigrams[0].as_ptr(), bigrams[2].as_ptr());
+    assert_ne!(bigrams[0].as_ptr(), bigrams[1].as_ptr());
+    assert!(a.ngrams(5).is_empty());
+    assert_eq!(a.ngrams(4)[0].as_ptr(), a.as_ptr());
+}
+
+#[test]
+fn test_intern_growth_monitor() {
+    let mut monitor = InternGrowthMonitor::new();
+    assert_eq!(monitor.rate(), 0.0);
+    for i in 0..500u64 {
+        let _ = Interned::from(i.to_string().as_str());
+    }
+    assert!(monitor.sample() > 0.0);
+    assert!(monitor.rate() > 0.0);
+    assert!(monitor.growth() >= 500);
+    // re-interning existing values does not grow the pool
+    for i in 0..500u64 {
+        let _ = Interned::from(i.to_string().as_str());
+    }
+    assert_eq!(monitor.sample(), 0.0);
+    assert!(total_interned() >= num_interned::<&str>());
+}
+
+#[test]
+fn test_assert_interned_bounded() {
+    for i in 0..10i16 {
+        let _ = Interned::from(i);
+    }
+    assert_interned_bounded::<i16>(10);
+    let result = std::panic::catch_unwind(|| assert_interned_bounded::<i16>(9));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_btree_map_orders_interned_by_value() {
+    use std::collections::BTreeMap;
+
+    let mut map: BTreeMap<Interned<&str>, usize> = BTreeMap::new();
+    for (i, word) in ["pear", "apple", "zucchini", "fig", "banana"]
+        .into_iter()
+        .enumerate()
+    {
+        map.insert(word.into(), i);
+    }
+    let keys: Vec<&str> = map.keys().map(|key| key.interned_str()).collect();
+    assert_eq!(keys, ["apple", "banana", "fig", "pear", "zucchini"]);
+    let apple: Interned<&str> = "apple".into();
+    let pear: Interned<&str> = "pear".into();
+    assert_eq!(apple.value_ord(&pear), std::cmp::Ordering::Less);
+    assert_eq!(pear.value_ord(&pear), std::cmp::Ordering::Equal);
+    assert_eq!(pear.value_ord(&apple), pear.cmp(&apple));
+
+    let mut numbers: BTreeMap<Interned<i64>, ()> = BTreeMap::new();
+    for n in [42i64, -7, 1000, 0, 3] {
+        numbers.insert(n.into(), ());
+    }
+    let keys: Vec<i64> = numbers.keys().map(|key| *key.interned_value()).collect();
+    assert_eq!(keys, [-7, 0, 3, 42, 1000]);
+}
+
+#[test]
+fn test_interned_slice_eq_vec_and_array() {
+    let slice: Interned<&[u32]> = [1u32, 2, 3].as_slice().into();
+    let same: Vec<u32> = (1..=3).collect();
+    assert!(slice.eq_vec(&same));
+    assert!(slice.eq_array(&[1, 2, 3]));
+    assert!(!slice.eq_vec(&same[..2]));
+    assert!(!slice.eq_array(&[3, 2, 1]));
+    let empty: Interned<&[u32]> = [0u32; 0].as_slice().into();
+    assert!(empty.eq_vec(&Vec::new()));
+    assert!(empty.eq_array(&[]));
+}
+
+#[test]
+fn test_memoized_map() {
+    use std::cell::Cell;
+
+    thread_local! {
+        static CALLS: Cell<usize> = const { Cell::new(0) };
+    }
+    for _ in 0..3 {
+        let len: Memoized<&str, usize> =
+            Memoized::from("memoized map len", "hello", |input| input.len().into());
+        let formatted: Memoized<&str, &str> = len.map(|len| {
+            CALLS.with(|calls| calls.set(calls.get() + 1));
+            Interned::from(format!("{len} chars").as_str())
+        });
+        assert_eq!(formatted.as_str(), "5 chars");
+        assert_eq!(formatted.as_ptr(), Interned::from("5 chars").as_ptr());
+    }
+    assert_eq!(CALLS.with(Cell::get), 1);
+
+    let other: Memoized<u8, usize> = Memoized::from("memoized map other", 7, |_| 5usize.into());
+    let doubled = other.map(|len| Interned::from(len * 2));
+    assert_eq!(*doubled, 10);
+
+    // zero-sized slices of different lengths share an address but must not share a result
+    let units: Memoized<usize, &[()]> =
+        Memoized::from("memoized map units", 3, |n| vec![(); n].as_slice().into());
+    let fewer: Memoized<usize, &[()]> =
+        Memoized::from("memoized map units", 1, |n| vec![(); n].as_slice().into());
+    assert_eq!(units.as_ptr(), fewer.as_ptr());
+    let count = |units: &[()]| Interned::from(units.len());
+    assert_eq!(*units.map(count), 3);
+    assert_eq!(*fewer.map(count), 1);
+}
+
+fn sum_interned_slice(values: impl InternedSlice<Item = i32>) -> i32 {
+    values.iter().sum()
+}
+
+#[test]
+fn test_interned_slice_trait() {
+    let values: Interned<&[i32]> = [4, -1, 10].as_slice().into();
+    assert_eq!(sum_interned_slice(values), 13);
+    assert_eq!(InternedSlice::len(&values), 3);
+    assert!(!InternedSlice::is_empty(&values));
+    assert_eq!(InternedSlice::get_elem(&values, 2), Some(&10));
+    assert_eq!(InternedSlice::get_elem(&values, 3), None);
+    // with the trait in scope, range indexing still resolves to the slice method
+    assert_eq!(values.get(1..3), Some([-1, 10].as_slice()));
+    assert_eq!(values.as_slice().as_ptr() as *const (), values.as_ptr());
+    let empty: Interned<&[i32]> = [0i32; 0].as_slice().into();
+    assert_eq!(sum_interned_slice(empty), 0);
+    assert!(InternedSlice::is_empty(&empty));
+    let words: Interned<&[&str]> = ["a", "b"].as_slice().into();
+    assert_eq!(
+        InternedSlice::iter(&words).copied().collect::<String>(),
+        "ab"
+    );
+}
+
+#[test]
+fn test_is_interned_ptr() {
+    let s: Interned<&str> = "is_interned_ptr probe".into();
+    let n: Interned<u64> = 0xfeed_u64.into();
+    assert!(is_interned_ptr(s.as_ptr()));
+    assert!(is_interned_ptr(n.as_ptr()));
+    assert!(is_interned_ptr(s.interned_str().as_ptr() as *const ()));
+    let local = String::from("is_interned_ptr probe");
+    assert!(!is_interned_ptr(local.as_ptr() as *const ()));
+    assert!(!is_interned_ptr(s.interned_str()[1..].as_ptr() as *const ()));
+    assert!(!is_interned_ptr(std::ptr::null()));
+}
+
+#[test]
+fn test_interned_slice_map_elements() {
+    let values: Interned<&[i32]> = [1, 2, 3].as_slice().into();
+    let doubled = values.map_elements(|x| x * 2);
+    assert_eq!(doubled.interned_slice(), [2, 4, 6]);
+    assert_eq!(
+        doubled.as_ptr(),
+        Interned::from([2, 4, 6].as_slice()).as_ptr()
+    );
+    let parity: Interned<&[bool]> = values.map_elements(|x| x % 2 == 0);
+    assert_eq!(parity.interned_slice(), [false, true, false]);
+    let empty: Interned<&[i32]> = [0i32; 0].as_slice().into();
+    assert!(empty.map_elements(|x| *x as u8).is_empty());
+}
+
+#[test]
+fn test_arena_interned_iterates_in_insertion_order() {
+    let handles: Vec<ArenaInterned<u64>> = (0..1000u64)
+        .map(|i| ArenaInterned::from(i * 7919 % 1000))
+        .collect();
+    assert_eq!(ArenaInterned::from(7919u64 % 1000), handles[1]);
+    let values: Vec<u64> = ArenaInterned::<u64>::iter()
+        .map(|value| *value.interned_value())
+        .collect();
+    let expected: Vec<u64> = (0..1000u64).map(|i| i * 7919 % 1000).collect();
+    assert_eq!(values, expected);
+    let walked: Vec<ArenaInterned<u64>> = ArenaInterned::<u64>::iter().collect();
+    assert_eq!(walked, handles);
+    // values within a chunk are laid out contiguously
+    let first = walked[0].as_ptr() as usize;
+    assert_eq!(walked[1].as_ptr() as usize, first + size_of::<u64>());
+}
+
+#[test]
+fn test_arena_interned_eq_across_threads() {
+    let local = ArenaInterned::from(4242u32);
+    let remote = std::thread::spawn(|| ArenaInterned::from(4242u32))
+        .join()
+        .unwrap();
+    assert_ne!(local.as_ptr(), remote.as_ptr());
+    assert_eq!(local, remote);
+    assert_ne!(local, ArenaInterned::from(4243u32));
+}
+
+#[test]
+fn test_interned_str_strip_affixes() {
+    let foobar: Interned<&str> = "foobar".into();
+    let bar = foobar.strip_prefix_interned("foo").unwrap();
+    assert_eq!(bar.as_ptr(), Interned::from("bar").as_ptr());
+    assert_eq!(foobar.strip_prefix_interned("bar"), None);
+    let foo = foobar.strip_suffix_interned("bar").unwrap();
+    assert_eq!(foo.as_ptr(), Interned::from("foo").as_ptr());
+    assert_eq!(foobar.strip_suffix_interned("foo"), None);
+    assert_eq!(
+        foobar.strip_prefix_interned("").unwrap().as_ptr(),
+        foobar.as_ptr()
+    );
+    assert_eq!(
+        foobar.strip_suffix_interned("foobar").unwrap(),
+        Interned::from("")
+    );
+}
+
+#[test]
+fn test_interned_layout_report() {
+    let words: Vec<Interned<&str>> = ["layout", "report", "entries"]
+        .into_iter()
+        .map(Interned::from)
+        .collect();
+    let report = interned_layout_report::<&str>();
+    assert_eq!(report.len(), num_interned::<&str>());
+    for word in &words {
+        assert!(report.contains(&(word.as_ptr(), word.len())));
+    }
+    let _: Interned<u64> = 42u64.into();
+    let _: Interned<&[u16]> = [1u16, 2, 3].as_slice().into();
+    let values = interned_layout_report::<u64>();
+    assert_eq!(values.len(), num_interned::<u64>());
+    assert!(values.iter().all(|&(_, size)| size == 8));
+    let slices = interned_layout_report::<&[u16]>();
+    assert_eq!(slices.len(), num_interned::<&[u16]>());
+    assert!(slices.iter().all(|&(_, size)| size > 0));
+    assert!(report.iter().any(|&(_, size)| size == "entries".len()));
+}
+
+#[test]
+fn test_interned_slice_from_iter_concatenates() {
+    let parts: [Interned<&[i32]>; 2] = [[1, 2].as_slice().into(), [3].as_slice().into()];
+    let whole: Interned<&[i32]> = parts.into_iter().collect();
+    assert_eq!(whole.interned_slice(), [1, 2, 3]);
+    assert_eq!(
+        whole.as_ptr(),
+        Interned::from([1, 2, 3].as_slice()).as_ptr()
+    );
+    let empty: Interned<&[i32]> = std::iter::empty().collect();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_interned_char_to_str() {
+    let a: Interned<char> = 'a'.into();
+    assert_eq!(a.to_str().as_ptr(), Interned::from("a").as_ptr());
+    let arrow: Interned<char> = '→'.into();
+    assert_eq!(arrow.to_str().interned_str(), "→");
+    assert_eq!(arrow.to_str().as_ptr(), arrow.to_str().as_ptr());
+}
+
+#[test]
+fn test_interned_str_char_indices() {
+    let s: Interned<&str> = "aé".into();
+    let indices: Vec<(usize, Interned<char>)> = s.char_indices_interned().collect();
+    assert_eq!(indices.len(), 2);
+    assert_eq!(indices[0].0, 0);
+    assert_eq!(indices[1].0, 1);
+    assert_eq!(*indices[0].1.interned_value(), 'a');
+    assert_eq!(*indices[1].1.interned_value(), 'é');
+    assert_eq!(indices[1].1.as_ptr(), Interned::from('é').as_ptr());
+    assert_eq!(indices[1].1.to_str().as_ptr(), Interned::from("é").as_ptr());
+}
+
+#[test]
+fn test_in_str_builder_snapshots() {
+    use std::fmt::Write;
+
+    let mut builder = InStrBuilder::new();
+    builder.push_str("ab");
+    let ab = builder.snapshot();
+    assert_eq!(ab, "ab");
+    assert_eq!(ab.as_ptr(), InStr::from("ab").as_ptr());
+    builder.push('c');
+    let abc = builder.snapshot();
+    assert_eq!(abc, "abc");
+    assert_eq!(builder.as_str(), "abc");
+    assert_eq!(builder.clone().finish().as_ptr(), abc.as_ptr());
+    write!(builder, "-{}", 1).unwrap();
+    assert_eq!(builder.finish(), InStr::from("abc-1"));
+    assert_eq!(InStrBuilder::with_capacity(8).finish(), "");
+}
+
+#[test]
+fn test_interned_slice_repeat_elem() {
+    let zeroes = Interned::<&[u8]>::repeat_elem(0u8, 4);
+    assert_eq!(
+        zeroes.as_ptr(),
+        Interned::from([0u8, 0, 0, 0].as_slice()).as_ptr()
+    );
+    assert_eq!(
+        Interned::<&[u8]>::repeat_elem(0, 4).as_ptr(),
+        zeroes.as_ptr()
+    );
+    assert_eq!(
+        Interned::<&[i64]>::repeat_elem(-1, 3).interned_slice(),
+        [-1, -1, -1]
+    );
+    assert!(Interned::<&[i64]>::repeat_elem(7, 0).is_empty());
+}
+
+#[test]
+fn test_interned_from_reporting() {
+    let (a, inserted) = Interned::<&str>::from_reporting("from_reporting");
+    assert!(inserted);
+    let (b, inserted) = Interned::<&str>::from_reporting("from_reporting");
+    assert!(!inserted);
+    assert_eq!(a.as_ptr(), b.as_ptr());
+    let (_, inserted) = Interned::from_reporting(Interned::from(77u64).interned_value() + 1);
+    assert!(inserted);
+    assert!(!Interned::from_reporting(77u64).1);
+    let (_, inserted) = Interned::from_reporting(true);
+    assert!(inserted);
+    assert!(!Interned::from_reporting(true).1);
+    assert!(Interned::from_reporting(3u8).1);
+    assert!(Interned::from_reporting(200u8).1);
+    assert!(!Interned::from_reporting(3u8).1);
+    assert!(!Interned::from_reporting(200u8).1);
+    let (c, inserted) = Interned::from_reporting(Colliding(1));
+    assert!(inserted);
+    let (d, inserted) = Interned::from_reporting(Colliding(2));
+    assert!(inserted);
+    assert_ne!(c.as_ptr(), d.as_ptr());
+    assert!(!Interned::from_reporting(Colliding(2)).1);
+}
+
+#[test]
+fn test_interned_str_intern_lines() {
+    let before: Interned<&str> = "fn main() {\n    old();\n}\n".into();
+    let after: Interned<&str> = "fn main() {\r\n    new();\r\n}".into();
+    let before_lines = before.intern_lines();
+    let after_lines = after.intern_lines();
+    assert_eq!(before_lines.len(), 3);
+    assert_eq!(after_lines.len(), 3);
+    assert_eq!(before_lines[0].as_ptr(), after_lines[0].as_ptr());
+    assert_ne!(before_lines[1].as_ptr(), after_lines[1].as_ptr());
+    assert_eq!(before_lines[2].as_ptr(), after_lines[2].as_ptr());
+    assert_eq!(after_lines[1], "    new();");
+}
+
+#[test]
+fn test_interned_slice_common_affixes() {
+    let a: Interned<&[i32]> = [1, 2, 3, 9].as_slice().into();
+    let b: Interned<&[i32]> = [1, 2, 3, 8].as_slice().into();
+    assert_eq!(a.longest_common_prefix(&b), 3);
+    assert_eq!(a.longest_common_suffix(&b), 0);
+    let c: Interned<&[i32]> = [7, 3, 9].as_slice().into();
+    assert_eq!(a.longest_common_prefix(&c), 0);
+    assert_eq!(a.longest_common_suffix(&c), 2);
+    assert_eq!(a.longest_common_prefix(&a), 4);
+    assert_eq!(a.longest_common_suffix(&a), 4);
+    let empty: Interned<&[i32]> = [0i32; 0].as_slice().into();
+    assert_eq!(a.longest_common_prefix(&empty), 0);
+    assert_eq!(empty.longest_common_suffix(&a), 0);
+}
+
+#[test]
+fn test_interned_str_retain_ascii_alnum() {
+    let messy: Interned<&str> = "a-b!c".into();
+    assert_eq!(
+        messy.retain_ascii_alnum().as_ptr(),
+        Interned::from("abc").as_ptr()
+    );
+    let other: Interned<&str> = "  a_b c ".into();
+    assert_eq!(
+        other.retain_ascii_alnum().as_ptr(),
+        messy.retain_ascii_alnum().as_ptr()
+    );
+    let clean: Interned<&str> = "Abc123".into();
+    assert_eq!(clean.retain_ascii_alnum().as_ptr(), clean.as_ptr());
+    let unicode: Interned<&str> = "é1ü2".into();
+    assert_eq!(unicode.retain_ascii_alnum().interned_str(), "12");
+}
+
+#[test]
+fn test_intern_entry() {
+    let mut vacant_seen = Vec::new();
+    for word in ["entry-x", "entry-y", "entry-x", "entry-z", "entry-y"] {
+        match Interned::entry(word) {
+            InternEntry::Occupied(existing) => assert_eq!(existing.interned_str(), word),
+            InternEntry::Vacant(entry) => {
+                vacant_seen.push(*entry.value());
+                entry.insert();
+            }
+        }
+    }
+    assert_eq!(vacant_seen, ["entry-x", "entry-y", "entry-z"]);
+
+    let before = num_interned::<u64>();
+    assert!(matches!(Interned::entry(404u64), InternEntry::Vacant(_)));
+    assert_eq!(num_interned::<u64>(), before);
+    let handle = Interned::entry(404u64).or_insert();
+    assert!(Interned::entry(404u64).is_occupied());
+    assert_eq!(
+        Interned::entry(404u64).or_insert().as_ptr(),
+        handle.as_ptr()
+    );
+
+    // `u8`s go through a dedicated table, which must agree with `from_reporting`
+    assert!(!Interned::entry(200u8).is_occupied());
+    assert!(Interned::from_reporting(200u8).1);
+    assert!(Interned::entry(200u8).is_occupied());
+    assert!(!Interned::from_reporting(200u8).1);
+    let byte = Interned::entry(201u8).or_insert();
+    assert!(!Interned::from_reporting(201u8).1);
+    assert_eq!(Interned::from(201u8).as_ptr(), byte.as_ptr());
+}
+
+#[test]
+fn test_interned_str_levenshtein() {
+    let kitten: Interned<&str> = "kitten".into();
+    let sitting: Interned<&str> = "sitting".into();
+    assert_eq!(kitten.levenshtein(&sitting), 3);
+    assert_eq!(sitting.levenshtein(&kitten), 3);
+    assert_eq!(kitten.levenshtein(&Interned::from("kitten")), 0);
+    let empty: Interned<&str> = "".into();
+    assert_eq!(empty.levenshtein(&kitten), 6);
+    assert_eq!(kitten.levenshtein(&empty), 6);
+    let accented: Interned<&str> = "kittén".into();
+    assert_eq!(kitten.levenshtein(&accented), 1);
+}
+
+#[test]
+fn test_interned_type_summary() {
+    for word in ["summary-a", "summary-b", "summary-a"] {
+        let _: Interned<&str> = word.into();
+    }
+    for n in [1i16, 2, 3, 2] {
+        let _: Interned<i16> = n.into();
+    }
+    let summary = interned_type_summary();
+    assert!(summary.contains(&("&str", num_interned::<&str>())));
+    assert!(summary.contains(&("i16", 3)));
+    assert!(summary.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    assert!(summary.iter().all(|&(name, _)| name != "<unknown>"));
+    assert!(!summary.iter().any(|&(name, _)| name == "i128"));
+}
+
+#[test]
+fn test_short_strings_are_packed() {
+    let words: Vec<String> = (0..1000).map(|i| format!("short-{i}")).collect();
+    let before = thread_allocations();
+    let short: Vec<Interned<&str>> = words.iter().map(|w| Interned::from(w.as_str())).collect();
+    // only the interner's map and a handful of shared chunks are allocated
+    assert!(thread_allocations() - before < 100);
+
+    let again = Interned::from(String::from("short-42").as_str());
+    assert_eq!(again, short[42]);
+    assert_eq!(again.as_ptr(), short[42].as_ptr());
+    assert_eq!(again.interned_str(), "short-42");
+    assert_ne!(short[1], short[2]);
+
+    let max = "a".repeat(_unsafe::SMALL_STR_MAX_LEN);
+    assert_eq!(Interned::from(max.as_str()).interned_str(), max);
+    assert_eq!(Interned::from("").interned_str(), "");
+}
+
+#[test]
+fn test_long_strings_dedupe_by_pointer() {
+    let words: Vec<String> = (0..100)
+        .map(|i| format!("a considerably longer string #{i}"))
+        .collect();
+    let before = thread_allocations();
+    let long: Vec<Interned<&str>> = words.iter().map(|w| Interned::from(w.as_str())).collect();
+    assert!(thread_allocations() - before >= 100);
+
+    let again = Interned::from(words[7].clone().as_str());
+    assert_eq!(again, long[7]);
+    assert_eq!(again.as_ptr(), long[7].as_ptr());
+    assert_ne!(long[7], long[8]);
+}
+
+#[test]
+fn test_interned_slice_intersect_union() {
+    let a: Interned<&[i32]> = Interned::from([1, 2, 3].as_slice());
+    let b: Interned<&[i32]> = Interned::from([2, 3, 4].as_slice());
+    let both = a.intersect(&b);
+    let either = a.union(&b);
+    assert_eq!(both.interned_slice(), [2, 3]);
+    assert_eq!(either.interned_slice(), [1, 2, 3, 4]);
+    assert_eq!(both.as_ptr(), Interned::from([2, 3].as_slice()).as_ptr());
+    assert_eq!(
+        either.as_ptr(),
+        Interned::from([1, 2, 3, 4].as_slice()).as_ptr()
+    );
+    assert_eq!(b.intersect(&a), both);
+    assert_eq!(b.union(&a), either);
+    assert_eq!(a.intersect(&a), a);
+    assert_eq!(a.union(&a), a);
+    let empty: Interned<&[i32]> = Interned::from([0i32; 0].as_slice());
+    assert_eq!(a.intersect(&empty), empty);
+    assert_eq!(a.union(&empty), a);
+    let units: Interned<&[()]> = Interned::from([(); 3].as_slice());
+    let fewer: Interned<&[()]> = Interned::from([(); 1].as_slice());
+    assert_eq!(units.intersect(&fewer), fewer);
+    assert_eq!(units.union(&fewer), units);
+    assert_eq!(fewer.union(&units), units);
+}
+
+#[test]
+fn test_interned_str_hash_with() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{BuildHasher, Hasher};
+
+    let interned: Interned<&str> = "bloom".into();
+    let mut expected = DefaultHasher::new();
+    "bloom".hash(&mut expected);
+    let mut actual = DefaultHasher::new();
+    interned.hash_with(&mut actual);
+    assert_eq!(actual.finish(), expected.finish());
+
+    let state = std::collections::hash_map::RandomState::new();
+    let mut actual = state.build_hasher();
+    interned.hash_with(&mut actual);
+    assert_eq!(actual.finish(), state.hash_one("bloom"));
+}
+
+#[test]
+fn test_interned_str_normalize_path_seps() {
+    let windows: Interned<&str> = "a\\b\\c.txt".into();
+    let unix: Interned<&str> = "a/b/c.txt".into();
+    assert_ne!(windows, unix);
+    let normalized = windows.normalize_path_seps();
+    assert_eq!(normalized.as_ptr(), unix.as_ptr());
+    assert_eq!(normalized.as_ptr(), unix.normalize_path_seps().as_ptr());
+    let mixed: Interned<&str> = "a\\b/c.txt".into();
+    assert_eq!(mixed.normalize_path_seps(), unix);
+}
+
+#[test]
+fn test_interned_slice_chunks_exact() {
+    let a: Interned<&[i32]> = Interned::from([1, 2, 3, 4, 5].as_slice());
+    let (chunks, remainder) = a.chunks_exact_interned::<2>();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].interned_slice(), [1, 2]);
+    assert_eq!(chunks[1].interned_slice(), [3, 4]);
+    assert_eq!(remainder.interned_slice(), [5]);
+    assert_eq!(
+        chunks[0].as_ptr(),
+        Interned::from([1, 2].as_slice()).as_ptr()
+    );
+
+    let repeated: Interned<&[i32]> = Interned::from([7, 8, 7, 8].as_slice());
+    let (chunks, remainder) = repeated.chunks_exact_interned::<2>();
+    assert_eq!(chunks[0].as_ptr(), chunks[1].as_ptr());
+    assert!(remainder.interned_slice().is_empty());
+}
+
+#[test]
+fn test_in_error() {
+    let error = InError::from("connection reset");
+    assert_eq!(error.to_string(), "connection reset");
+    assert_eq!(format!("{error:?}"), "InError(\"connection reset\")");
+    let again = InError::from(String::from("connection reset"));
+    assert_eq!(error, again);
+    assert_eq!(error.message().as_ptr(), again.message().as_ptr());
+
+    let boxed: Box<dyn std::error::Error> = Box::new(error);
+    assert_eq!(boxed.to_string(), "connection reset");
+    assert!(boxed.source().is_none());
+    assert_eq!(boxed.downcast_ref::<InError>(), Some(&error));
+}
+
+#[test]
+fn test_interned_slice_from_exact_iter() {
+    let collected = Interned::<&[i32]>::from_exact_iter(0..3);
+    let direct: Interned<&[i32]> = Interned::from([0, 1, 2].as_slice());
+    assert_eq!(collected, direct);
+    assert_eq!(collected.as_ptr(), direct.as_ptr());
+    let doubled = Interned::<&[i32]>::from_exact_iter([1, 2, 3].iter().map(|x| x * 2));
+    assert_eq!(doubled.interned_slice(), [2, 4, 6]);
+    let empty = Interned::<&[i32]>::from_exact_iter(std::iter::empty::<i32>());
+    assert!(empty.interned_slice().is_empty());
+}
+
+#[test]
+fn test_drain_scope() {
+    let double = |n: i64| Interned::from(n * 2);
+    let a = Memoized::from_drainable("drain-double", 1i64, double);
+    let b = Memoized::from_drainable("drain-double", 2i64, double);
+    let _ = Memoized::from_drainable("drain-double", 1i64, double);
+    let kept = Memoized::from_drainable("drain-keep", 1i64, double);
+    // only values memoized via `from_drainable` are recorded for draining
+    let untracked = Memoized::from("drain-double", 3i64, double);
+    let before = num_memoized::<i64>();
+
+    let drained = drain_scope::<i64>("drain-double");
+    assert_eq!(drained, [a.interned(), b.interned()]);
+    assert_eq!(num_memoized::<i64>(), before - 2);
+    assert!(Memoized::<i64, i64>::get("drain-double", 1i64).is_none());
+    assert!(Memoized::<i64, i64>::get("drain-double", 2i64).is_none());
+    assert_eq!(
+        Memoized::<i64, i64>::get("drain-double", 3i64).map(|m| m.as_ptr()),
+        Some(untracked.as_ptr())
+    );
+    assert_eq!(
+        Memoized::<i64, i64>::get("drain-keep", 1i64).map(|m| m.as_ptr()),
+        Some(kept.as_ptr())
+    );
+    // the values themselves stay interned
+    assert_eq!(Interned::from(4i64).as_ptr(), b.as_ptr());
+    assert!(drain_scope::<i64>("drain-double").is_empty());
+
+    let again = Memoized::from_drainable("drain-double", 2i64, double);
+    assert_eq!(again.as_ptr(), b.as_ptr());
+    assert_eq!(drain_scope::<i64>("drain-double"), [b.interned()]);
+}
+
+#[test]
+fn test_interned_str_starts_with_interned() {
+    let foobar: Interned<&str> = "foobar".into();
+    assert!(foobar.starts_with_interned(&"foo".into()));
+    assert!(!foobar.starts_with_interned(&"bar".into()));
+    assert!(foobar.starts_with_interned(&foobar));
+    assert!(foobar.starts_with_interned(&"".into()));
+    assert!(!foobar.starts_with_interned(&"foobarbaz".into()));
+    let accented: Interned<&str> = "caf\u{e9}".into();
+    assert!(accented.starts_with_interned(&"caf".into()));
+}
+
+#[test]
+fn test_interned_str_to_cstring() {
+    let hello: Interned<&str> = "hello".into();
+    let c_hello = hello.to_cstring().unwrap();
+    assert_eq!(c_hello.to_bytes(), b"hello");
+    assert_eq!(c_hello.to_bytes_with_nul(), b"hello\0");
+    assert_eq!(c_hello.to_str(), Ok("hello"));
+    let again = Interned::<&str>::from("hello").to_cstring().unwrap();
+    assert_eq!(again.as_ptr(), c_hello.as_ptr());
+    assert_eq!(
+        Interned::<&str>::from("").to_cstring().unwrap().to_bytes(),
+        b""
+    );
+
+    let embedded: Interned<&str> = "hel\0lo".into();
+    let error = embedded.to_cstring().unwrap_err();
+    assert_eq!(error.nul_position(), 3);
+}
+
+#[test]
+fn test_interned_str_byte_offset_of() {
+    let foobar: Interned<&str> = "foobar".into();
+    assert_eq!(foobar.byte_offset_of("bar"), Some(3));
+    assert_eq!(foobar.byte_offset_of("foo"), Some(0));
+    assert_eq!(foobar.byte_offset_of("baz"), None);
+    assert_eq!(foobar.byte_offset_of(""), Some(0));
+    let offset = foobar.byte_offset_of("bar").unwrap();
+    let span = &foobar.interned_str()[offset..offset + 3];
+    assert_eq!(span.as_ptr(), unsafe {
+        (foobar.as_ptr() as *const u8).add(3)
+    });
+    let accented: Interned<&str> = "caf\u{e9} bar".into();
+    assert_eq!(accented.byte_offset_of("bar"), Some(6));
+}